@@ -0,0 +1,119 @@
+// Copyright 2026 The hamcrest-rust Developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! ANSI colors for failure messages, enabled by the `color` feature.
+
+use std::env;
+use std::io::{self, IsTerminal};
+
+use core::{Fragment, Style};
+
+const RESET: &str = "\x1b[0m";
+const BOLD: &str = "\x1b[1m";
+const RED: &str = "\x1b[31m";
+const GREEN: &str = "\x1b[32m";
+const BOLD_RED: &str = "\x1b[1;31m";
+const BOLD_GREEN: &str = "\x1b[1;32m";
+const INVERSE_RED: &str = "\x1b[7;31m";
+const INVERSE_GREEN: &str = "\x1b[7;32m";
+
+/// Whether failure messages should be colored.
+///
+/// A non-empty `NO_COLOR` disables colors, a `CLICOLOR_FORCE` other than `0`
+/// enables them, otherwise they are used if stderr is a terminal.
+pub fn enabled() -> bool {
+    from_env(
+        env::var_os("NO_COLOR").map(|value| !value.is_empty()),
+        env::var_os("CLICOLOR_FORCE").map(|value| value != "0"),
+    ).unwrap_or_else(|| io::stderr().is_terminal())
+}
+
+fn from_env(no_color: Option<bool>, force: Option<bool>) -> Option<bool> {
+    match (no_color, force) {
+        (Some(true), _) => Some(false),
+        (_, Some(true)) => Some(true),
+        _ => None,
+    }
+}
+
+/// Colors the headings and the values and diffs of descriptions.
+pub struct Ansi;
+
+impl Style for Ansi {
+    fn expected(&self, text: &str) -> String {
+        paint(BOLD_GREEN, text)
+    }
+
+    fn but(&self, text: &str) -> String {
+        paint(BOLD_RED, text)
+    }
+
+    fn fragment(&self, fragment: &Fragment) -> String {
+        match *fragment {
+            Fragment::Text(ref text) => text.clone(),
+            Fragment::Value(ref text) => paint(BOLD, text),
+            Fragment::Removed(ref text) => paint(RED, text),
+            Fragment::Added(ref text) => paint(GREEN, text),
+            Fragment::RemovedChars(ref text) => paint(INVERSE_RED, text),
+            Fragment::AddedChars(ref text) => paint(INVERSE_GREEN, text),
+        }
+    }
+}
+
+fn paint(color: &str, text: &str) -> String {
+    format!("{}{}{}", color, text, RESET)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::check_that;
+    use matchers::equal_to::equal_to;
+
+    #[test]
+    fn no_color_wins_over_clicolor_force() {
+        assert_eq!(from_env(Some(true), Some(true)), Some(false));
+        assert_eq!(from_env(Some(true), None), Some(false));
+    }
+
+    #[test]
+    fn clicolor_force_enables_colors() {
+        assert_eq!(from_env(None, Some(true)), Some(true));
+        assert_eq!(from_env(Some(false), Some(true)), Some(true));
+    }
+
+    #[test]
+    fn otherwise_the_terminal_decides() {
+        assert_eq!(from_env(None, None), None);
+        assert_eq!(from_env(Some(false), Some(false)), None);
+    }
+
+    #[test]
+    fn ansi_colors_headings_and_values() {
+        let error = check_that(2, equal_to(1)).unwrap_err();
+
+        assert_eq!(
+            error.render(&Ansi),
+            "\x1b[1;32mExpected:\x1b[0m 1\n    \x1b[1;31mbut:\x1b[0m was \x1b[1m2\x1b[0m"
+        );
+        assert_eq!(error.to_string(), "Expected: 1\n    but: was 2");
+    }
+
+    #[test]
+    fn ansi_colors_diffs() {
+        assert_eq!(
+            Ansi.fragment(&Fragment::Removed("-  a".to_string())),
+            "\x1b[31m-  a\x1b[0m"
+        );
+        assert_eq!(
+            Ansi.fragment(&Fragment::AddedChars("b".to_string())),
+            "\x1b[7;32mb\x1b[0m"
+        );
+        assert_eq!(Ansi.fragment(&Fragment::Text("was".to_string())), "was");
+    }
+}
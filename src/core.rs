@@ -8,28 +8,461 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use std::fmt;
+use std::error::Error;
+use std::fmt::{self, Write};
+use std::panic::Location;
 
-pub type MatchResult = Result<(), String>;
+use matchers::all_of::{all_of, AllOf};
+use matchers::any_of::{any_of, AnyOf};
+use matchers::described_as::{described_as, DescribedAs};
+use matchers::is::{is_not, IsNot};
+
+pub type MatchResult = Result<(), Description>;
 
 pub fn success() -> MatchResult {
     Ok(())
 }
 
-pub fn expect(predicate: bool, msg: String) -> MatchResult {
-    if predicate { success() } else { Err(msg) }
+pub fn expect<D: Into<Description>>(predicate: bool, msg: D) -> MatchResult {
+    if predicate { success() } else { Err(msg.into()) }
 }
 
 #[deprecated(since = "0.1.2", note = "Use the assert_that! macro instead")]
 pub fn assert_that<T, U: Matcher<T>>(actual: T, matcher: U) {
+    if let Err(error) = check_that(actual, matcher) {
+        panic!("\n{}", error.render_for_terminal());
+    }
+}
+
+/// Checks `actual` against `matcher` and returns the failure as an error
+/// instead of panicking.
+///
+/// The `check_that!` macro also records the tested expression and accepts a
+/// reason, like `assert_that!`.
+#[track_caller]
+pub fn check_that<T, M: Matcher<T>>(actual: T, matcher: M) -> Result<(), AssertionError> {
+    let location = Location::caller();
+    check_that_at(
+        None,
+        None,
+        (location.file(), location.line(), location.column()),
+        actual,
+        matcher,
+    )
+}
+
+/// Used by `check_that!` and `assert_that!`.
+#[doc(hidden)]
+pub fn check_that_at<T, M: Matcher<T>>(
+    reason: Option<fmt::Arguments>,
+    actual_expr: Option<&str>,
+    location: (&'static str, u32, u32),
+    actual: T,
+    matcher: M,
+) -> Result<(), AssertionError> {
     match matcher.matches(actual) {
-        Ok(_) => return,
-        Err(mismatch) => {
-            panic!("\nExpected: {}\n    but: {}", matcher, mismatch);
+        Ok(_) => Ok(()),
+        Err(mismatch) => Err(AssertionError {
+            reason: reason.map(|reason| reason.to_string()),
+            actual: actual_expr.map(str::to_string),
+            expected: matcher.to_string(),
+            mismatch: Box::new(mismatch),
+            file: location.0,
+            line: location.1,
+            column: location.2,
+        }),
+    }
+}
+
+/// A failed assertion, as returned by `check_that` and `check_that!`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AssertionError {
+    reason: Option<String>,
+    actual: Option<String>,
+    expected: String,
+    mismatch: Box<Description>,
+    file: &'static str,
+    line: u32,
+    column: u32,
+}
+
+impl AssertionError {
+    /// The reason given to `check_that!`, if any.
+    pub fn reason(&self) -> Option<&str> {
+        self.reason.as_ref().map(|s| &s[..])
+    }
+
+    /// The source code of the tested expression, if it was checked with a
+    /// macro.
+    pub fn actual(&self) -> Option<&str> {
+        self.actual.as_ref().map(|s| &s[..])
+    }
+
+    /// The description of the matcher.
+    pub fn expected(&self) -> &str {
+        &self.expected
+    }
+
+    pub fn mismatch(&self) -> &Description {
+        &self.mismatch
+    }
+
+    pub fn file(&self) -> &'static str {
+        self.file
+    }
+
+    pub fn line(&self) -> u32 {
+        self.line
+    }
+
+    pub fn column(&self) -> u32 {
+        self.column
+    }
+}
+
+impl AssertionError {
+    /// Renders the error for a panic message, with ANSI colors if the `color`
+    /// feature is enabled and stderr supports them.
+    #[doc(hidden)]
+    pub fn render_for_terminal(&self) -> String {
+        #[cfg(feature = "color")]
+        {
+            if ::color::enabled() {
+                return self.render(&::color::Ansi);
+            }
         }
+        self.to_string()
+    }
+
+    pub(crate) fn render(&self, style: &dyn Style) -> String {
+        let mut out = String::new();
+        if let Some(ref reason) = self.reason {
+            out.push_str(reason);
+            out.push('\n');
+        }
+        if let Some(ref actual) = self.actual {
+            out.push_str(&format!("Value of: {}\n", actual));
+        }
+        out.push_str(&style.expected("Expected:"));
+        out.push(' ');
+        out.push_str(&self.expected);
+        out.push_str("\n    ");
+        out.push_str(&style.but("but:"));
+        out.push(' ');
+        out.push_str(&self.mismatch.render_with(9, style));
+        out
+    }
+}
+
+impl fmt::Display for AssertionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.render(&Plain))
     }
 }
 
+impl Error for AssertionError {}
+
 pub trait Matcher<T>: fmt::Display {
     fn matches(&self, actual: T) -> MatchResult;
+
+    /// Checks whether `actual` matches without describing a mismatch.
+    ///
+    /// Matchers that can answer this more cheaply than `matches` should
+    /// override it, composite matchers use it when they only need a boolean.
+    fn is_match(&self, actual: T) -> bool {
+        self.matches(actual).is_ok()
+    }
+
+    /// Appends a description of the values accepted by this matcher.
+    fn describe_to(&self, description: Description) -> Description {
+        description.append_text(self.to_string())
+    }
+
+    /// Describes why `actual` does not match, the description is empty if it
+    /// does match.
+    fn describe_mismatch(&self, actual: T) -> Description {
+        self.matches(actual).err().unwrap_or_default()
+    }
+
+    /// Describes why `actual` matches, or returns `None` if it does not.
+    ///
+    /// This is what negating matchers such as `is_not` report as their
+    /// mismatch.
+    fn describe_match(&self, actual: T) -> Option<Description> {
+        if self.is_match(actual) {
+            Some(self.describe_to(Description::new().append_text("matched ")))
+        } else {
+            None
+        }
+    }
+}
+
+impl<T, M: Matcher<T> + ?Sized> Matcher<T> for Box<M> {
+    fn matches(&self, actual: T) -> MatchResult {
+        (**self).matches(actual)
+    }
+
+    fn is_match(&self, actual: T) -> bool {
+        (**self).is_match(actual)
+    }
+
+    fn describe_to(&self, description: Description) -> Description {
+        (**self).describe_to(description)
+    }
+
+    fn describe_mismatch(&self, actual: T) -> Description {
+        (**self).describe_mismatch(actual)
+    }
+
+    fn describe_match(&self, actual: T) -> Option<Description> {
+        (**self).describe_match(actual)
+    }
+}
+
+/// Combinators for chaining matchers from left to right.
+///
+/// ```
+/// # #[macro_use] extern crate hamcrest;
+/// # use hamcrest::prelude::*;
+/// # fn main() {
+/// assert_that!(4, greater_than(3).and(less_than(5)));
+/// assert_that!(1, less_than(2).or(greater_than(3)));
+/// assert_that!(1, equal_to(2).not().described_as("anything but two"));
+/// # }
+/// ```
+pub trait MatcherExt<T>: Matcher<T> + Sized {
+    /// Matches if both `self` and `other` match, see `all_of`.
+    fn and<M: Matcher<T>>(self, other: M) -> AllOf<T, (Self, M)> {
+        all_of((self, other))
+    }
+
+    /// Matches if either `self` or `other` matches, see `any_of`.
+    fn or<M: Matcher<T>>(self, other: M) -> AnyOf<T, (Self, M)> {
+        any_of((self, other))
+    }
+
+    /// Matches if `self` does not match, see `is_not`.
+    fn not(self) -> IsNot<T, Self> {
+        is_not(self)
+    }
+
+    /// Replaces the description of `self` in failure messages.
+    fn described_as<S: Into<String>>(self, description: S) -> DescribedAs<T, Self> {
+        described_as(description, self)
+    }
+
+    /// Erases the type of `self`, e.g. to keep different matchers in a `Vec`.
+    fn boxed<'a>(self) -> Box<dyn Matcher<T> + 'a>
+    where
+        Self: 'a,
+    {
+        Box::new(self)
+    }
+}
+
+impl<T, M: Matcher<T>> MatcherExt<T> for M {}
+
+/// A piece of the text of a `Description`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Fragment {
+    /// Plain explanatory text.
+    Text(String),
+    /// A `Debug` formatted value, usually the value under test.
+    Value(String),
+    /// Part of a diff line that is only in the expected value.
+    Removed(String),
+    /// Part of a diff line that is only in the actual value.
+    Added(String),
+    /// Characters of a removed line that differ from the line replacing it.
+    RemovedChars(String),
+    /// Characters of an added line that differ from the line it replaces.
+    AddedChars(String),
+}
+
+impl fmt::Display for Fragment {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Fragment::Text(ref text) |
+            Fragment::Value(ref text) |
+            Fragment::Removed(ref text) |
+            Fragment::Added(ref text) |
+            Fragment::RemovedChars(ref text) |
+            Fragment::AddedChars(ref text) => f.write_str(text),
+        }
+    }
+}
+
+/// Decorates the text of failure messages.
+pub(crate) trait Style {
+    /// Styles the "Expected:" heading.
+    fn expected(&self, text: &str) -> String;
+
+    /// Styles the "but:" heading.
+    fn but(&self, text: &str) -> String;
+
+    fn fragment(&self, fragment: &Fragment) -> String;
+}
+
+/// Renders text as is.
+pub(crate) struct Plain;
+
+impl Style for Plain {
+    fn expected(&self, text: &str) -> String {
+        text.to_string()
+    }
+
+    fn but(&self, text: &str) -> String {
+        text.to_string()
+    }
+
+    fn fragment(&self, fragment: &Fragment) -> String {
+        fragment.to_string()
+    }
+}
+
+/// Describes why a value failed to match.
+///
+/// A description is made of text fragments and, optionally, a label, the
+/// expected and actual values and any number of nested descriptions. Matchers
+/// composed of other matchers nest the descriptions of their children, which
+/// are rendered as an indented tree.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Description {
+    label: Option<String>,
+    fragments: Vec<Fragment>,
+    expected: Option<String>,
+    actual: Option<String>,
+    children: Vec<Description>,
+}
+
+impl Description {
+    pub fn new() -> Description {
+        Description::default()
+    }
+
+    pub fn append_text<S: Into<String>>(mut self, text: S) -> Description {
+        self.fragments.push(Fragment::Text(text.into()));
+        self
+    }
+
+    pub fn append_value<T: fmt::Debug + ?Sized>(mut self, value: &T) -> Description {
+        self.fragments.push(Fragment::Value(format!("{:?}", value)));
+        self
+    }
+
+    pub fn append_fragment(mut self, fragment: Fragment) -> Description {
+        self.fragments.push(fragment);
+        self
+    }
+
+    pub fn append_child(mut self, child: Description) -> Description {
+        self.children.push(child);
+        self
+    }
+
+    pub fn with_label<S: Into<String>>(mut self, label: S) -> Description {
+        self.label = Some(label.into());
+        self
+    }
+
+    pub fn with_expected<S: Into<String>>(mut self, expected: S) -> Description {
+        self.expected = Some(expected.into());
+        self
+    }
+
+    pub fn with_actual<S: Into<String>>(mut self, actual: S) -> Description {
+        self.actual = Some(actual.into());
+        self
+    }
+
+    pub fn label(&self) -> Option<&str> {
+        self.label.as_ref().map(|s| &s[..])
+    }
+
+    pub fn fragments(&self) -> &[Fragment] {
+        &self.fragments
+    }
+
+    pub fn expected(&self) -> Option<&str> {
+        self.expected.as_ref().map(|s| &s[..])
+    }
+
+    pub fn actual(&self) -> Option<&str> {
+        self.actual.as_ref().map(|s| &s[..])
+    }
+
+    pub fn children(&self) -> &[Description] {
+        &self.children
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.label.is_none() && self.fragments.is_empty() && self.expected.is_none() &&
+            self.actual.is_none() && self.children.is_empty()
+    }
+
+    /// Renders the description as an indented tree.
+    ///
+    /// The first line is not indented, every following line is indented by
+    /// `indent` spaces plus two more for each level of nesting.
+    pub fn render(&self, indent: usize) -> String {
+        self.render_with(indent, &Plain)
+    }
+
+    pub(crate) fn render_with(&self, indent: usize, style: &dyn Style) -> String {
+        let mut out = String::new();
+        self.write_tree(&mut out, indent, style).unwrap();
+        out
+    }
+
+    fn write_tree(&self, out: &mut String, indent: usize, style: &dyn Style) -> fmt::Result {
+        if let Some(ref label) = self.label {
+            out.push_str(label);
+            if !self.fragments.is_empty() {
+                out.push_str(": ");
+            }
+        }
+        for fragment in &self.fragments {
+            out.push_str(&style.fragment(fragment));
+        }
+
+        let indent = indent + 2;
+        if let Some(ref expected) = self.expected {
+            write!(out, "\n{:indent$}expected: {}", "", expected, indent = indent)?;
+        }
+        if let Some(ref actual) = self.actual {
+            write!(out, "\n{:indent$}  actual: {}", "", actual, indent = indent)?;
+        }
+        for child in &self.children {
+            write!(out, "\n{:indent$}", "", indent = indent)?;
+            child.write_tree(out, indent, style)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Description {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.render(0))
+    }
+}
+
+impl From<String> for Description {
+    fn from(text: String) -> Description {
+        Description::new().append_text(text)
+    }
+}
+
+impl<'a> From<&'a str> for Description {
+    fn from(text: &'a str) -> Description {
+        Description::new().append_text(text)
+    }
+}
+
+/// Labels the description of the content of an enum variant with `variant`.
+pub(crate) fn in_variant(variant: &str, description: Description) -> Description {
+    if description.label().is_none() {
+        description.with_label(variant)
+    } else {
+        Description::new().with_label(variant).append_child(description)
+    }
 }
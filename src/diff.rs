@@ -0,0 +1,258 @@
+// Copyright 2026 The hamcrest-rust Developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Line by line diffs of `Debug` output, for matchers comparing big values.
+
+use core::{Description, Fragment};
+
+/// Unchanged lines kept around each change.
+const CONTEXT: usize = 3;
+
+/// The largest table of line pairs compared, bigger inputs are not diffed.
+const MAX_CELLS: usize = 1 << 20;
+
+#[derive(Clone, Copy, PartialEq)]
+enum Op<'a> {
+    Equal(&'a str),
+    Delete(&'a str),
+    Insert(&'a str),
+}
+
+impl<'a> Op<'a> {
+    fn line(&self) -> &'a str {
+        match *self {
+            Op::Equal(line) | Op::Delete(line) | Op::Insert(line) => line,
+        }
+    }
+}
+
+/// Describes the differences between `expected` and `actual` as a unified
+/// diff, one nested description per line.
+///
+/// Returns `None` if they have the same lines or too many lines changed to
+/// diff them cheaply.
+pub fn diff(expected: &str, actual: &str) -> Option<Description> {
+    let (expected, expected_newline) = split_final_newline(expected);
+    let (actual, actual_newline) = split_final_newline(actual);
+    let expected: Vec<&str> = expected.split('\n').collect();
+    let actual: Vec<&str> = actual.split('\n').collect();
+    let ops = diff_lines(&expected, &actual)?;
+    if ops.iter().all(|op| matches!(*op, Op::Equal(_))) {
+        return None;
+    }
+
+    let mut description = Description::new().append_text("was different (-expected +actual):");
+    let mut skipped = false;
+    let mut i = 0;
+    while i < ops.len() {
+        match ops[i] {
+            Op::Equal(line) => {
+                if near_change(&ops, i) {
+                    description = description
+                        .append_child(Description::new().append_text(format!("  {}", line)));
+                    skipped = false;
+                } else if !skipped {
+                    description = description.append_child(Description::new().append_text("  ..."));
+                    skipped = true;
+                }
+                i += 1;
+            }
+            _ => {
+                let deleted = count(&ops[i..], |op| matches!(*op, Op::Delete(_)));
+                let inserted = count(&ops[i + deleted..], |op| matches!(*op, Op::Insert(_)));
+                let lines = changed_lines(
+                    &ops[i..i + deleted],
+                    &ops[i + deleted..i + deleted + inserted],
+                );
+                for line in lines {
+                    description = description.append_child(line);
+                }
+                skipped = false;
+                i += deleted + inserted;
+            }
+        }
+    }
+
+    if expected_newline != actual_newline {
+        let which = if expected_newline { "expected" } else { "actual" };
+        description = description.append_child(Description::new().append_text(format!(
+            "  (only the {} value ends with a newline)",
+            which
+        )));
+    }
+
+    Some(description)
+}
+
+/// Splits off a final line break, so that text ending with one does not end
+/// with an empty line.
+fn split_final_newline(text: &str) -> (&str, bool) {
+    match text.strip_suffix('\n') {
+        Some(text) => (text, true),
+        None => (text, false),
+    }
+}
+
+fn count<F: Fn(&Op) -> bool>(ops: &[Op], f: F) -> usize {
+    ops.iter().take_while(|op| f(op)).count()
+}
+
+fn near_change(ops: &[Op], index: usize) -> bool {
+    let start = index.saturating_sub(CONTEXT);
+    let end = (index + CONTEXT + 1).min(ops.len());
+    ops[start..end]
+        .iter()
+        .any(|op| !matches!(*op, Op::Equal(_)))
+}
+
+/// Renders a block of deleted lines followed by the lines that replaced them,
+/// highlighting the characters that changed in lines that were edited.
+fn changed_lines(deleted: &[Op], inserted: &[Op]) -> Vec<Description> {
+    let mut removed = Vec::new();
+    let mut added = Vec::new();
+    for (i, op) in deleted.iter().enumerate() {
+        let old = op.line();
+        match inserted.get(i) {
+            Some(new) => {
+                let new = new.line();
+                let (prefix, suffix) = common_affixes(old, new);
+                removed.push(edited_line(
+                    "- ",
+                    old,
+                    prefix,
+                    suffix,
+                    Fragment::Removed,
+                    Fragment::RemovedChars,
+                ));
+                added.push(edited_line(
+                    "+ ",
+                    new,
+                    prefix,
+                    suffix,
+                    Fragment::Added,
+                    Fragment::AddedChars,
+                ));
+            }
+            None => removed
+                .push(Description::new().append_fragment(Fragment::Removed(format!("- {}", old)))),
+        }
+    }
+    for op in inserted.iter().skip(deleted.len()) {
+        added.push(Description::new().append_fragment(Fragment::Added(format!("+ {}", op.line()))));
+    }
+
+    removed.extend(added);
+    removed
+}
+
+fn edited_line<F, G>(
+    marker: &str,
+    line: &str,
+    prefix: usize,
+    suffix: usize,
+    unchanged: F,
+    changed: G,
+) -> Description
+where
+    F: Fn(String) -> Fragment,
+    G: Fn(String) -> Fragment,
+{
+    let end = line.len() - suffix;
+    let mut description =
+        Description::new().append_fragment(unchanged(format!("{}{}", marker, &line[..prefix])));
+    if prefix < end {
+        description = description.append_fragment(changed(line[prefix..end].to_string()));
+    }
+    if end < line.len() {
+        description = description.append_fragment(unchanged(line[end..].to_string()));
+    }
+    description
+}
+
+/// The length in bytes of the longest common prefix and suffix of `a` and
+/// `b`, which do not overlap.
+fn common_affixes(a: &str, b: &str) -> (usize, usize) {
+    let prefix: usize = a
+        .chars()
+        .zip(b.chars())
+        .take_while(|&(x, y)| x == y)
+        .map(|(x, _)| x.len_utf8())
+        .sum();
+    let suffix: usize = a[prefix..]
+        .chars()
+        .rev()
+        .zip(b[prefix..].chars().rev())
+        .take_while(|&(x, y)| x == y)
+        .map(|(x, _)| x.len_utf8())
+        .sum();
+    (prefix, suffix)
+}
+
+/// A longest common subsequence diff of two lists of lines, or `None` if the
+/// lines between their common prefix and suffix are too many to compare.
+fn diff_lines<'a>(expected: &[&'a str], actual: &[&'a str]) -> Option<Vec<Op<'a>>> {
+    let prefix = expected
+        .iter()
+        .zip(actual)
+        .take_while(|&(e, a)| e == a)
+        .count();
+    let suffix = expected[prefix..]
+        .iter()
+        .rev()
+        .zip(actual[prefix..].iter().rev())
+        .take_while(|&(e, a)| e == a)
+        .count();
+    let (n, m) = (expected.len() - prefix - suffix, actual.len() - prefix - suffix);
+    if n.saturating_mul(m) > MAX_CELLS {
+        return None;
+    }
+
+    let mut ops: Vec<Op> = expected[..prefix].iter().map(|line| Op::Equal(line)).collect();
+    ops.extend(lcs_diff(
+        &expected[prefix..prefix + n],
+        &actual[prefix..prefix + m],
+    ));
+    ops.extend(expected[prefix + n..].iter().map(|line| Op::Equal(line)));
+    Some(ops)
+}
+
+/// Compares every pair of lines, so it takes `expected.len() * actual.len()`
+/// time and memory.
+fn lcs_diff<'a>(expected: &[&'a str], actual: &[&'a str]) -> Vec<Op<'a>> {
+    let (n, m) = (expected.len(), actual.len());
+    // lengths[i][j] is the length of the LCS of expected[i..] and actual[j..].
+    let mut lengths = vec![vec![0usize; m + 1]; n + 1];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            lengths[i][j] = if expected[i] == actual[j] {
+                lengths[i + 1][j + 1] + 1
+            } else {
+                lengths[i + 1][j].max(lengths[i][j + 1])
+            };
+        }
+    }
+
+    let mut ops = Vec::with_capacity(n.max(m));
+    let (mut i, mut j) = (0, 0);
+    while i < n && j < m {
+        if expected[i] == actual[j] {
+            ops.push(Op::Equal(expected[i]));
+            i += 1;
+            j += 1;
+        } else if lengths[i + 1][j] >= lengths[i][j + 1] {
+            ops.push(Op::Delete(expected[i]));
+            i += 1;
+        } else {
+            ops.push(Op::Insert(actual[j]));
+            j += 1;
+        }
+    }
+    ops.extend(expected[i..].iter().map(|line| Op::Delete(line)));
+    ops.extend(actual[j..].iter().map(|line| Op::Insert(line)));
+    ops
+}
@@ -17,36 +17,101 @@ extern crate regex;
 
 pub use prelude::*;
 
+/// Panics if `actual` does not match `matcher`.
+///
+/// An optional reason, with `format!` style arguments, is printed above the
+/// failure message:
+///
+/// ```
+/// # #[macro_use] extern crate hamcrest;
+/// # use hamcrest::prelude::*;
+/// # fn main() {
+/// let (id, code) = (7, 200);
+/// assert_that!(code, equal_to(200));
+/// assert_that!(code, equal_to(200), "request {} failed", id);
+/// # }
+/// ```
 #[macro_export]
 macro_rules! assert_that {
-    ($actual:expr, $matcher:expr) => ({
-        // The separate statement is necessary to keep the compiler happy.
-        let m = $matcher;
-        match m.matches($actual) {
-            Ok(_) => {},
-            Err(mismatch) => {
-                // The panic macro produces the correct file and line number
-                // when used in a macro like this, i.e. it's the line where
-                // the macro was originally written.
-                panic!("\nExpected: {}\n    but: {}", m, mismatch);
-            }
+    ($actual:expr, $matcher:expr $(,)*) => ({
+        if let Err(error) = $crate::check_that!($actual, $matcher) {
+            // The panic macro produces the correct file and line number
+            // when used in a macro like this, i.e. it's the line where
+            // the macro was originally written.
+            panic!("\n{}", error.render_for_terminal());
         }
     }
     );
+    ($actual:expr, $matcher:expr, $($reason:tt)+) => ({
+        if let Err(error) = $crate::check_that!($actual, $matcher, $($reason)+) {
+            panic!("\n{}", error.render_for_terminal());
+        }
+    }
+    );
+}
+
+/// Like `assert_that!`, but returns a `Result<(), AssertionError>` instead of
+/// panicking.
+///
+/// ```
+/// # #[macro_use] extern crate hamcrest;
+/// # use hamcrest::prelude::*;
+/// # use hamcrest::core::AssertionError;
+/// fn validate(port: u16) -> Result<(), AssertionError> {
+///     check_that!(port, greater_than(1023), "port {} is reserved", port)?;
+///     check_that!(port, not(equal_to(8080)))
+/// }
+/// # fn main() {
+/// # assert!(validate(8000).is_ok());
+/// # assert!(validate(80).is_err());
+/// # }
+/// ```
+#[macro_export]
+macro_rules! check_that {
+    ($actual:expr, $matcher:expr $(,)*) => (
+        $crate::core::check_that_at(
+            None,
+            Some(stringify!($actual)),
+            (file!(), line!(), column!()),
+            $actual,
+            $matcher,
+        )
+    );
+    ($actual:expr, $matcher:expr, $($reason:tt)+) => (
+        $crate::core::check_that_at(
+            Some(format_args!($($reason)+)),
+            Some(stringify!($actual)),
+            (file!(), line!(), column!()),
+            $actual,
+            $matcher,
+        )
+    );
 }
 
+#[cfg(feature = "color")]
+mod color;
 pub mod core;
+mod diff;
 pub mod matchers;
+pub mod soft_assertions;
 pub mod prelude {
     #[allow(deprecated)]
     pub use core::assert_that;
+    pub use core::check_that;
+    pub use core::Description;
     pub use core::Matcher as HamcrestMatcher;
+    pub use core::MatcherExt;
+    pub use soft_assertions::SoftAssertions;
     pub use matchers::close_to::close_to;
     pub use matchers::compared_to::less_than;
     pub use matchers::compared_to::less_than_or_equal_to;
     pub use matchers::compared_to::greater_than;
     pub use matchers::compared_to::greater_than_or_equal_to;
+    pub use matchers::described_as::described_as;
     pub use matchers::equal_to::equal_to;
+    pub use matchers::error::caused_by;
+    pub use matchers::error::error_kind;
+    pub use matchers::error::error_message;
     pub use matchers::existing_path::existing_dir;
     pub use matchers::existing_path::existing_file;
     pub use matchers::existing_path::existing_path;
@@ -54,15 +119,44 @@ pub mod prelude {
     pub use matchers::is::is_not as not;
     pub use matchers::is::is_not;
     pub use matchers::is::is;
+    pub use matchers::items::contains_exactly_matching;
+    pub use matchers::items::contains_in_any_order;
+    pub use matchers::items::has_items;
     pub use matchers::none::none;
+    pub use matchers::panics::panics;
+    pub use matchers::panics::panics_with;
+    pub use matchers::result::err;
+    pub use matchers::result::is_err;
+    pub use matchers::result::is_ok;
+    pub use matchers::result::ok;
+    pub use matchers::some::some;
+    pub use matchers::some::some_with;
+    pub use matchers::regex::captures;
+    pub use matchers::regex::fully_matches_regex;
+    pub use matchers::regex::matches_all_patterns;
+    pub use matchers::regex::matches_any_pattern;
     pub use matchers::regex::matches_regex as match_regex;
     pub use matchers::regex::matches_regex;
+    pub use matchers::regex::named_capture;
+    pub use matchers::strings::blank_string;
+    pub use matchers::strings::contains_string;
+    pub use matchers::strings::empty_or_none_string;
+    pub use matchers::strings::empty_string;
+    pub use matchers::strings::ends_with;
+    pub use matchers::strings::equal_to_string;
+    pub use matchers::strings::equal_to_ignoring_case;
+    pub use matchers::strings::equal_to_ignoring_whitespace;
+    pub use matchers::strings::starts_with;
+    pub use matchers::strings::string_contains_in_order;
+    pub use matchers::strings::trimmed;
     pub use matchers::vecs::contains;
     pub use matchers::vecs::of_len;
     pub use matchers::anything::anything;
     pub use matchers::type_of::type_of;
     pub use matchers::all_of::all_of;
     pub use matchers::all_of::all_of as and;
+    pub use matchers::all_of::all_of_ref;
     pub use matchers::any_of::any_of;
     pub use matchers::any_of::any_of as or;
+    pub use matchers::any_of::any_of_ref;
 }
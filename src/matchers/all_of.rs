@@ -10,184 +10,174 @@ use std::fmt::{self, Display};
 use std::marker::PhantomData;
 
 use core::*;
+use matchers::matcher_list::{fmt_list, MatcherList};
 
-pub struct AllOf<T, M>(M, PhantomData<T>);
-
-pub fn all_of<T, M>(matchers: M) -> AllOf<T, M> {
-    AllOf(matchers, PhantomData)
+pub struct AllOf<T, M> {
+    matchers: M,
+    report_all: bool,
+    marker: PhantomData<T>,
 }
 
-#[macro_export]
-macro_rules! all_of {
-    ($( $arg:expr ),*) => ($crate::matchers::all_of::all_of(($( $arg ),*)))
+impl<T, M> AllOf<T, M> {
+    /// Checks every matcher and reports all mismatches instead of stopping
+    /// at the first one.
+    pub fn report_all(mut self) -> AllOf<T, M> {
+        self.report_all = true;
+        self
+    }
 }
 
-impl<T, M0, M1> Display for AllOf<T, (M0, M1)>
-where
-    M0: Display,
-    M1: Display,
-{
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let (ref m0, ref m1) = self.0;
-
-        write!(f, "all of ({}, {})", m0, m1)
+/// Matches if all of `matchers` match.
+///
+/// `matchers` is a tuple, `Vec` or array of matchers, see `MatcherList`. The
+/// value under test is cloned for every matcher, pass a reference or use
+/// `all_of_ref` if it is expensive or impossible to clone.
+pub fn all_of<T, M>(matchers: M) -> AllOf<T, M> {
+    AllOf {
+        matchers,
+        report_all: false,
+        marker: PhantomData,
     }
 }
 
-impl<T, M0, M1> Matcher<T> for AllOf<T, (M0, M1)>
-where
-    T: Clone,
-    M0: Matcher<T>,
-    M1: Matcher<T>,
-{
-    fn matches(&self, actual: T) -> MatchResult {
-        let (ref m0, ref m1) = self.0;
-
-        m0.matches(actual.clone())?;
-        m1.matches(actual.clone())?;
+/// Like `AllOf`, but lends the value under test to its matchers instead of
+/// cloning it.
+pub struct AllOfRef<T, M> {
+    matchers: M,
+    report_all: bool,
+    marker: PhantomData<T>,
+}
 
-        success()
+impl<T, M> AllOfRef<T, M> {
+    /// Checks every matcher and reports all mismatches instead of stopping
+    /// at the first one.
+    pub fn report_all(mut self) -> AllOfRef<T, M> {
+        self.report_all = true;
+        self
     }
 }
 
-impl<T, M0, M1, M2> Display for AllOf<T, (M0, M1, M2)>
-where
-    M0: Display,
-    M1: Display,
-    M2: Display,
-{
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let (ref m0, ref m1, ref m2) = self.0;
-
-        write!(f, "all of ({}, {}, {})", m0, m1, m2)
+/// Matches a `T` if all of `matchers` match a reference to it.
+///
+/// Unlike `all_of` this does not require `T: Clone`. The matchers must accept
+/// references of any lifetime, i.e. implement `for<'a> Matcher<&'a T>`.
+pub fn all_of_ref<T, M>(matchers: M) -> AllOfRef<T, M> {
+    AllOfRef {
+        matchers,
+        report_all: false,
+        marker: PhantomData,
     }
 }
 
-impl<T, M0, M1, M2> Matcher<T> for AllOf<T, (M0, M1, M2)>
-where
-    T: Clone,
-    M0: Matcher<T>,
-    M1: Matcher<T>,
-    M2: Matcher<T>,
-{
-    fn matches(&self, actual: T) -> MatchResult {
-        let (ref m0, ref m1, ref m2) = self.0;
+#[macro_export]
+macro_rules! all_of {
+    ($( $arg:expr ),*) => ($crate::matchers::all_of::all_of(($( $arg ),*)))
+}
+
+#[macro_export]
+macro_rules! all_of_ref {
+    ($( $arg:expr ),*) => ($crate::matchers::all_of::all_of_ref(($( $arg ),*)))
+}
 
-        m0.matches(actual.clone())?;
-        m1.matches(actual.clone())?;
-        m2.matches(actual.clone())?;
+fn mismatch<T>(matcher: &dyn Matcher<T>, mismatch: Description) -> Description {
+    Description::new()
+        .append_text("did not match:")
+        .append_child(mismatch.with_label(matcher.to_string()))
+}
 
-        success()
+fn matches_list<T: Clone, M: MatcherList<T>>(
+    matchers: &M,
+    report_all: bool,
+    actual: T,
+) -> MatchResult {
+    if !report_all {
+        for i in 0..matchers.len() {
+            let matcher = matchers.get(i);
+            matcher
+                .matches(actual.clone())
+                .map_err(|e| mismatch(matcher, e))?;
+        }
+
+        return success();
     }
-}
 
-impl<T, M0, M1, M2, M3> Display for AllOf<T, (M0, M1, M2, M3)>
-where
-    M0: Display,
-    M1: Display,
-    M2: Display,
-    M3: Display,
-{
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let (ref m0, ref m1, ref m2, ref m3) = self.0;
+    let mut failed = 0;
+    let mut description = Description::new();
+    for i in 0..matchers.len() {
+        let matcher = matchers.get(i);
+        if let Err(e) = matcher.matches(actual.clone()) {
+            failed += 1;
+            description = description.append_child(e.with_label(format!("[{}] {}", i, matcher)));
+        }
+    }
 
-        write!(f, "all of ({}, {}, {}, {})", m0, m1, m2, m3)
+    if failed == 0 {
+        success()
+    } else {
+        Err(description.append_text(format!("did not match {} of {}:", failed, matchers.len())))
     }
 }
 
-impl<T, M0, M1, M2, M3> Matcher<T> for AllOf<T, (M0, M1, M2, M3)>
-where
-    T: Clone,
-    M0: Matcher<T>,
-    M1: Matcher<T>,
-    M2: Matcher<T>,
-    M3: Matcher<T>,
-{
-    fn matches(&self, actual: T) -> MatchResult {
-        let (ref m0, ref m1, ref m2, ref m3) = self.0;
-
-        m0.matches(actual.clone())?;
-        m1.matches(actual.clone())?;
-        m2.matches(actual.clone())?;
-        m3.matches(actual.clone())?;
+fn is_match_list<T: Clone, M: MatcherList<T>>(matchers: &M, actual: T) -> bool {
+    (0..matchers.len()).all(|i| matchers.get(i).is_match(actual.clone()))
+}
 
-        success()
+fn describe_match_list<T: Clone, M: MatcherList<T>>(
+    matchers: &M,
+    actual: T,
+) -> Option<Description> {
+    let mut description = Description::new().append_text("matched all of:");
+    for i in 0..matchers.len() {
+        let matcher = matchers.get(i);
+        let matched = matcher.describe_match(actual.clone())?;
+        description = description.append_child(matched.with_label(matcher.to_string()));
     }
+
+    Some(description)
 }
 
-impl<T, M0, M1, M2, M3, M4> Display for AllOf<T, (M0, M1, M2, M3, M4)>
-where
-    M0: Display,
-    M1: Display,
-    M2: Display,
-    M3: Display,
-    M4: Display,
-{
+impl<T, M: MatcherList<T>> Display for AllOf<T, M> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let (ref m0, ref m1, ref m2, ref m3, ref m4) = self.0;
-
-        write!(f, "all of ({}, {}, {}, {}, {})", m0, m1, m2, m3, m4)
+        fmt_list("all of", &self.matchers, f)
     }
 }
 
-impl<T, M0, M1, M2, M3, M4> Matcher<T> for AllOf<T, (M0, M1, M2, M3, M4)>
-where
-    T: Clone,
-    M0: Matcher<T>,
-    M1: Matcher<T>,
-    M2: Matcher<T>,
-    M3: Matcher<T>,
-    M4: Matcher<T>,
-{
+impl<T: Clone, M: MatcherList<T>> Matcher<T> for AllOf<T, M> {
     fn matches(&self, actual: T) -> MatchResult {
-        let (ref m0, ref m1, ref m2, ref m3, ref m4) = self.0;
+        matches_list(&self.matchers, self.report_all, actual)
+    }
 
-        m0.matches(actual.clone())?;
-        m1.matches(actual.clone())?;
-        m2.matches(actual.clone())?;
-        m3.matches(actual.clone())?;
-        m4.matches(actual.clone())?;
+    fn is_match(&self, actual: T) -> bool {
+        is_match_list(&self.matchers, actual)
+    }
 
-        success()
+    fn describe_match(&self, actual: T) -> Option<Description> {
+        describe_match_list(&self.matchers, actual)
     }
 }
 
-impl<T, M0, M1, M2, M3, M4, M5> Display for AllOf<T, (M0, M1, M2, M3, M4, M5)>
+impl<T, M> Display for AllOfRef<T, M>
 where
-    M0: Display,
-    M1: Display,
-    M2: Display,
-    M3: Display,
-    M4: Display,
-    M5: Display,
+    M: for<'a> MatcherList<&'a T>,
 {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let (ref m0, ref m1, ref m2, ref m3, ref m4, ref m5) = self.0;
-
-        write!(f, "all of ({}, {}, {}, {}, {}, {})", m0, m1, m2, m3, m4, m5)
+        fmt_list("all of", &self.matchers, f)
     }
 }
 
-impl<T, M0, M1, M2, M3, M4, M5> Matcher<T> for AllOf<T, (M0, M1, M2, M3, M4, M5)>
+impl<T, M> Matcher<T> for AllOfRef<T, M>
 where
-    T: Clone,
-    M0: Matcher<T>,
-    M1: Matcher<T>,
-    M2: Matcher<T>,
-    M3: Matcher<T>,
-    M4: Matcher<T>,
-    M5: Matcher<T>,
+    M: for<'a> MatcherList<&'a T>,
 {
     fn matches(&self, actual: T) -> MatchResult {
-        let (ref m0, ref m1, ref m2, ref m3, ref m4, ref m5) = self.0;
+        matches_list(&self.matchers, self.report_all, &actual)
+    }
 
-        m0.matches(actual.clone())?;
-        m1.matches(actual.clone())?;
-        m2.matches(actual.clone())?;
-        m3.matches(actual.clone())?;
-        m4.matches(actual.clone())?;
-        m5.matches(actual.clone())?;
+    fn is_match(&self, actual: T) -> bool {
+        is_match_list(&self.matchers, &actual)
+    }
 
-        success()
+    fn describe_match(&self, actual: T) -> Option<Description> {
+        describe_match_list(&self.matchers, &actual)
     }
 }
@@ -10,175 +10,125 @@ use std::fmt::{self, Display};
 use std::marker::PhantomData;
 
 use core::*;
+use matchers::matcher_list::{fmt_list, MatcherList};
 
 pub struct AnyOf<T, M>(M, PhantomData<T>);
 
+/// Matches if any of `matchers` matches.
+///
+/// `matchers` is a tuple, `Vec` or array of matchers, see `MatcherList`. The
+/// value under test is cloned for every matcher, pass a reference or use
+/// `any_of_ref` if it is expensive or impossible to clone.
 pub fn any_of<T, M>(matchers: M) -> AnyOf<T, M> {
     AnyOf(matchers, PhantomData)
 }
 
+/// Like `AnyOf`, but lends the value under test to its matchers instead of
+/// cloning it.
+pub struct AnyOfRef<T, M>(M, PhantomData<T>);
+
+/// Matches a `T` if any of `matchers` matches a reference to it.
+///
+/// Unlike `any_of` this does not require `T: Clone`. The matchers must accept
+/// references of any lifetime, i.e. implement `for<'a> Matcher<&'a T>`.
+pub fn any_of_ref<T, M>(matchers: M) -> AnyOfRef<T, M> {
+    AnyOfRef(matchers, PhantomData)
+}
+
 #[macro_export]
 macro_rules! any_of {
     ($( $arg:expr ),*) => ($crate::matchers::any_of::any_of(($( $arg ),*)))
 }
 
-impl<T, M0, M1> Display for AnyOf<T, (M0, M1)>
-where
-    M0: Display,
-    M1: Display,
-{
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let (ref m0, ref m1) = self.0;
-
-        write!(f, "any of ({}, {})", m0, m1)
-    }
+#[macro_export]
+macro_rules! any_of_ref {
+    ($( $arg:expr ),*) => ($crate::matchers::any_of::any_of_ref(($( $arg ),*)))
 }
 
-impl<T, M0, M1> Matcher<T> for AnyOf<T, (M0, M1)>
-where
-    T: Clone,
-    M0: Matcher<T>,
-    M1: Matcher<T>,
-{
-    fn matches(&self, actual: T) -> MatchResult {
-        let (ref m0, ref m1) = self.0;
+fn matched<T>(matcher: &dyn Matcher<T>, description: Description) -> Description {
+    Description::new()
+        .append_text("matched:")
+        .append_child(description.with_label(matcher.to_string()))
+}
 
-        m0.matches(actual.clone()).or_else(
-            |_| m1.matches(actual.clone()),
-        )
+fn matches_list<T: Clone, M: MatcherList<T>>(matchers: &M, actual: T) -> MatchResult {
+    if is_match_list(matchers, actual.clone()) {
+        return success();
     }
-}
 
-impl<T, M0, M1, M2> Display for AnyOf<T, (M0, M1, M2)>
-where
-    M0: Display,
-    M1: Display,
-    M2: Display,
-{
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let (ref m0, ref m1, ref m2) = self.0;
+    if matchers.is_empty() {
+        return Err(Description::new().append_text("had no matchers to match"));
+    }
 
-        write!(f, "any of ({}, {}, {})", m0, m1, m2)
+    let mut description = Description::new().append_text("did not match any:");
+    for i in 0..matchers.len() {
+        let matcher = matchers.get(i);
+        description = description.append_child(
+            matcher
+                .describe_mismatch(actual.clone())
+                .with_label(format!("[{}] {}", i, matcher)),
+        );
     }
+
+    Err(description)
 }
 
-impl<T, M0, M1, M2> Matcher<T> for AnyOf<T, (M0, M1, M2)>
-where
-    T: Clone,
-    M0: Matcher<T>,
-    M1: Matcher<T>,
-    M2: Matcher<T>,
-{
-    fn matches(&self, actual: T) -> MatchResult {
-        let (ref m0, ref m1, ref m2) = self.0;
+fn is_match_list<T: Clone, M: MatcherList<T>>(matchers: &M, actual: T) -> bool {
+    (0..matchers.len()).any(|i| matchers.get(i).is_match(actual.clone()))
+}
 
-        m0.matches(actual.clone())
-            .or_else(|_| m1.matches(actual.clone()))
-            .or_else(|_| m2.matches(actual.clone()))
-    }
+fn describe_match_list<T: Clone, M: MatcherList<T>>(
+    matchers: &M,
+    actual: T,
+) -> Option<Description> {
+    (0..matchers.len())
+        .map(|i| matchers.get(i))
+        .filter_map(|m| m.describe_match(actual.clone()).map(|d| matched(m, d)))
+        .next()
 }
 
-impl<T, M0, M1, M2, M3> Display for AnyOf<T, (M0, M1, M2, M3)>
-where
-    M0: Display,
-    M1: Display,
-    M2: Display,
-    M3: Display,
-{
+impl<T, M: MatcherList<T>> Display for AnyOf<T, M> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let (ref m0, ref m1, ref m2, ref m3) = self.0;
-
-        write!(f, "any of ({}, {}, {}, {})", m0, m1, m2, m3)
+        fmt_list("any of", &self.0, f)
     }
 }
 
-impl<T, M0, M1, M2, M3> Matcher<T> for AnyOf<T, (M0, M1, M2, M3)>
-where
-    T: Clone,
-    M0: Matcher<T>,
-    M1: Matcher<T>,
-    M2: Matcher<T>,
-    M3: Matcher<T>,
-{
+impl<T: Clone, M: MatcherList<T>> Matcher<T> for AnyOf<T, M> {
     fn matches(&self, actual: T) -> MatchResult {
-        let (ref m0, ref m1, ref m2, ref m3) = self.0;
+        matches_list(&self.0, actual)
+    }
 
-        m0.matches(actual.clone())
-            .or_else(|_| m1.matches(actual.clone()))
-            .or_else(|_| m2.matches(actual.clone()))
-            .or_else(|_| m3.matches(actual.clone()))
+    fn is_match(&self, actual: T) -> bool {
+        is_match_list(&self.0, actual)
+    }
+
+    fn describe_match(&self, actual: T) -> Option<Description> {
+        describe_match_list(&self.0, actual)
     }
 }
 
-impl<T, M0, M1, M2, M3, M4> Display for AnyOf<T, (M0, M1, M2, M3, M4)>
+impl<T, M> Display for AnyOfRef<T, M>
 where
-    M0: Display,
-    M1: Display,
-    M2: Display,
-    M3: Display,
-    M4: Display,
+    M: for<'a> MatcherList<&'a T>,
 {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let (ref m0, ref m1, ref m2, ref m3, ref m4) = self.0;
-
-        write!(f, "any of ({}, {}, {}, {}, {})", m0, m1, m2, m3, m4)
+        fmt_list("any of", &self.0, f)
     }
 }
 
-impl<T, M0, M1, M2, M3, M4> Matcher<T> for AnyOf<T, (M0, M1, M2, M3, M4)>
+impl<T, M> Matcher<T> for AnyOfRef<T, M>
 where
-    T: Clone,
-    M0: Matcher<T>,
-    M1: Matcher<T>,
-    M2: Matcher<T>,
-    M3: Matcher<T>,
-    M4: Matcher<T>,
+    M: for<'a> MatcherList<&'a T>,
 {
     fn matches(&self, actual: T) -> MatchResult {
-        let (ref m0, ref m1, ref m2, ref m3, ref m4) = self.0;
-
-        m0.matches(actual.clone())
-            .or_else(|_| m1.matches(actual.clone()))
-            .or_else(|_| m2.matches(actual.clone()))
-            .or_else(|_| m3.matches(actual.clone()))
-            .or_else(|_| m4.matches(actual.clone()))
+        matches_list(&self.0, &actual)
     }
-}
-
-impl<T, M0, M1, M2, M3, M4, M5> Display for AnyOf<T, (M0, M1, M2, M3, M4, M5)>
-where
-    M0: Display,
-    M1: Display,
-    M2: Display,
-    M3: Display,
-    M4: Display,
-    M5: Display,
-{
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let (ref m0, ref m1, ref m2, ref m3, ref m4, ref m5) = self.0;
 
-        write!(f, "any of ({}, {}, {}, {}, {}, {})", m0, m1, m2, m3, m4, m5)
+    fn is_match(&self, actual: T) -> bool {
+        is_match_list(&self.0, &actual)
     }
-}
 
-impl<T, M0, M1, M2, M3, M4, M5> Matcher<T> for AnyOf<T, (M0, M1, M2, M3, M4, M5)>
-where
-    T: Clone,
-    M0: Matcher<T>,
-    M1: Matcher<T>,
-    M2: Matcher<T>,
-    M3: Matcher<T>,
-    M4: Matcher<T>,
-    M5: Matcher<T>,
-{
-    fn matches(&self, actual: T) -> MatchResult {
-        let (ref m0, ref m1, ref m2, ref m3, ref m4, ref m5) = self.0;
-
-        m0.matches(actual.clone())
-            .or_else(|_| m1.matches(actual.clone()))
-            .or_else(|_| m2.matches(actual.clone()))
-            .or_else(|_| m3.matches(actual.clone()))
-            .or_else(|_| m4.matches(actual.clone()))
-            .or_else(|_| m5.matches(actual.clone()))
+    fn describe_match(&self, actual: T) -> Option<Description> {
+        describe_match_list(&self.0, &actual)
     }
 }
@@ -21,6 +21,10 @@ impl<T> Matcher<T> for Anything {
     fn matches(&self, _: T) -> MatchResult {
         success()
     }
+
+    fn is_match(&self, _: T) -> bool {
+        true
+    }
 }
 
 /// always matches, useful if you don't care what the object under test is
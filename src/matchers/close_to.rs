@@ -30,34 +30,52 @@ impl<T: Debug> Display for CloseTo<T> {
     }
 }
 
-impl<T: Float + Zero + Debug> Matcher<T> for CloseTo<T> {
-    fn matches(&self, actual: T) -> MatchResult {
+impl<T: Float + Zero> CloseTo<T> {
+    fn is_close(&self, actual: T) -> bool {
         let a = self.expected.abs();
         let b = actual.abs();
 
         let d = (a - b).abs();
 
-        let close =
-            // shortcut, handles infinities
-            a == b
+        // shortcut, handles infinities
+        a == b
             // a or b is zero or both are extremely close to it
             // relative error is less meaningful here
             || ((a == Zero::zero() || b == Zero::zero() || d < Float::min_positive_value()) &&
                 d < (self.epsilon * Float::min_positive_value()))
             // use relative error
-            || d / (a + b).min(Float::max_value()) < self.epsilon;
+            || d / (a + b).min(Float::max_value()) < self.epsilon
+    }
+}
 
-        if close {
+impl<T: Float + Zero + Debug> Matcher<T> for CloseTo<T> {
+    fn matches(&self, actual: T) -> MatchResult {
+        if self.is_close(actual) {
             success()
         } else {
-            Err(format!("was {:?}", actual))
+            Err(Description::new().append_text("was ").append_value(&actual))
+        }
+    }
+
+    fn is_match(&self, actual: T) -> bool {
+        self.is_close(actual)
+    }
+
+    fn describe_match(&self, actual: T) -> Option<Description> {
+        if self.is_close(actual) {
+            Some(
+                Description::new()
+                    .append_text("was ")
+                    .append_value(&actual)
+                    .append_text(", which is close to ")
+                    .append_value(&self.expected),
+            )
+        } else {
+            None
         }
     }
 }
 
 pub fn close_to<T>(expected: T, epsilon: T) -> CloseTo<T> {
-    CloseTo {
-        expected: expected,
-        epsilon: epsilon,
-    }
+    CloseTo { expected, epsilon }
 }
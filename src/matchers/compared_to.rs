@@ -39,19 +39,40 @@ impl<T: fmt::Debug> fmt::Display for ComparedTo<T> {
     }
 }
 
+impl<T: PartialOrd> ComparedTo<T> {
+    fn compare(&self, actual: &T) -> bool {
+        match self.operation {
+            CompareOperation::LessOrEqual => *actual <= self.right_hand_side,
+            CompareOperation::LessThan => *actual < self.right_hand_side,
+            CompareOperation::GreaterOrEqual => *actual >= self.right_hand_side,
+            CompareOperation::GreaterThan => *actual > self.right_hand_side,
+        }
+    }
+}
+
 impl<T: PartialOrd + fmt::Debug> Matcher<T> for ComparedTo<T> {
     fn matches(&self, actual: T) -> MatchResult {
-        let it_succeeded = match self.operation {
-            CompareOperation::LessOrEqual => actual <= self.right_hand_side,
-            CompareOperation::LessThan => actual < self.right_hand_side,
-            CompareOperation::GreaterOrEqual => actual >= self.right_hand_side,
-            CompareOperation::GreaterThan => actual > self.right_hand_side,
-        };
-
-        if it_succeeded {
+        if self.compare(&actual) {
             success()
         } else {
-            Err(format!("was {:?}", actual))
+            Err(Description::new().append_text("was ").append_value(&actual))
+        }
+    }
+
+    fn is_match(&self, actual: T) -> bool {
+        self.compare(&actual)
+    }
+
+    fn describe_match(&self, actual: T) -> Option<Description> {
+        if self.compare(&actual) {
+            Some(
+                Description::new()
+                    .append_text("was ")
+                    .append_value(&actual)
+                    .append_text(format!(", which is {}", self)),
+            )
+        } else {
+            None
         }
     }
 }
@@ -59,27 +80,27 @@ impl<T: PartialOrd + fmt::Debug> Matcher<T> for ComparedTo<T> {
 pub fn less_than<T: PartialOrd + fmt::Debug>(right_hand_side: T) -> ComparedTo<T> {
     ComparedTo {
         operation: CompareOperation::LessThan,
-        right_hand_side: right_hand_side,
+        right_hand_side,
     }
 }
 
 pub fn less_than_or_equal_to<T: PartialOrd + fmt::Debug>(right_hand_side: T) -> ComparedTo<T> {
     ComparedTo {
         operation: CompareOperation::LessOrEqual,
-        right_hand_side: right_hand_side,
+        right_hand_side,
     }
 }
 
 pub fn greater_than<T: PartialOrd + fmt::Debug>(right_hand_side: T) -> ComparedTo<T> {
     ComparedTo {
         operation: CompareOperation::GreaterThan,
-        right_hand_side: right_hand_side,
+        right_hand_side,
     }
 }
 
 pub fn greater_than_or_equal_to<T: PartialOrd + fmt::Debug>(right_hand_side: T) -> ComparedTo<T> {
     ComparedTo {
         operation: CompareOperation::GreaterOrEqual,
-        right_hand_side: right_hand_side,
+        right_hand_side,
     }
 }
@@ -0,0 +1,54 @@
+// Copyright 2026 The hamcrest-rust Developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::fmt;
+use std::marker::PhantomData;
+
+use core::*;
+
+/// Replaces the description of a matcher, its mismatches are left untouched.
+pub struct DescribedAs<T, M> {
+    description: String,
+    matcher: M,
+    marker: PhantomData<T>,
+}
+
+impl<T, M> fmt::Display for DescribedAs<T, M> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.description)
+    }
+}
+
+impl<T, M: Matcher<T>> Matcher<T> for DescribedAs<T, M> {
+    fn matches(&self, actual: T) -> MatchResult {
+        self.matcher.matches(actual)
+    }
+
+    fn is_match(&self, actual: T) -> bool {
+        self.matcher.is_match(actual)
+    }
+
+    fn describe_mismatch(&self, actual: T) -> Description {
+        self.matcher.describe_mismatch(actual)
+    }
+
+    fn describe_match(&self, actual: T) -> Option<Description> {
+        self.matcher.describe_match(actual)
+    }
+}
+
+pub fn described_as<T, S: Into<String>, M: Matcher<T>>(
+    description: S,
+    matcher: M,
+) -> DescribedAs<T, M> {
+    DescribedAs {
+        description: description.into(),
+        matcher,
+        marker: PhantomData,
+    }
+}
@@ -12,6 +12,10 @@
 use std::fmt;
 
 use core::*;
+use diff::diff;
+
+/// Values whose `Debug` output is longer than this are compared with a diff.
+const MAX_INLINE_LEN: usize = 60;
 
 pub struct EqualTo<T> {
     expected: T,
@@ -28,11 +32,80 @@ impl<T: PartialEq + fmt::Debug> Matcher<T> for EqualTo<T> {
         if self.expected.eq(&actual) {
             success()
         } else {
-            Err(format!("was {:?}", actual))
+            Err(mismatch(&self.expected, &actual))
+        }
+    }
+
+    fn is_match(&self, actual: T) -> bool {
+        self.expected.eq(&actual)
+    }
+
+    fn describe_match(&self, actual: T) -> Option<Description> {
+        if self.expected.eq(&actual) {
+            Some(
+                Description::new()
+                    .append_text("was ")
+                    .append_value(&actual)
+                    .append_text(", which is ")
+                    .append_value(&self.expected),
+            )
+        } else {
+            None
         }
     }
 }
 
+/// Describes `actual` inline if it and `expected` are short, and as a diff
+/// against `expected` otherwise. Multi-line strings are diffed line by line.
+fn mismatch(expected: &dyn fmt::Debug, actual: &dyn fmt::Debug) -> Description {
+    let expected_text = format!("{:?}", expected);
+    let actual_text = format!("{:?}", actual);
+    let difference = match (string_text(&expected_text), string_text(&actual_text)) {
+        (Some(e), Some(a)) if e.contains('\n') || a.contains('\n') => diff(&e, &a),
+        _ if is_short(&expected_text) && is_short(&actual_text) => None,
+        _ => diff(&format!("{:#?}", expected), &format!("{:#?}", actual)),
+    };
+    difference.unwrap_or_else(|| {
+        Description::new()
+            .append_text("was ")
+            .append_fragment(Fragment::Value(actual_text))
+    })
+}
+
+/// The text of a string from its `Debug` output, or `None` if it is not a
+/// string. Escaped quotes, backslashes and newlines are restored, other
+/// escapes are kept so invisible characters stay visible.
+fn string_text(debug: &str) -> Option<String> {
+    if debug.len() < 2 || !debug.starts_with('"') || !debug.ends_with('"') {
+        return None;
+    }
+
+    let mut text = String::with_capacity(debug.len());
+    let mut chars = debug[1..debug.len() - 1].chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            text.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => text.push('\n'),
+            Some(escaped @ '"') | Some(escaped @ '\'') | Some(escaped @ '\\') => {
+                text.push(escaped)
+            }
+            Some(escaped) => {
+                text.push('\\');
+                text.push(escaped);
+            }
+            None => text.push('\\'),
+        }
+    }
+    Some(text)
+}
+
+fn is_short(value: &str) -> bool {
+    value.len() <= MAX_INLINE_LEN && !value.contains('\n')
+}
+
 pub fn equal_to<T: PartialEq + fmt::Debug>(expected: T) -> EqualTo<T> {
-    EqualTo { expected: expected }
+    EqualTo { expected }
 }
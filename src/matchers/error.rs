@@ -0,0 +1,283 @@
+// Copyright 2026 The hamcrest-rust Developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Matchers for `std::error::Error`s.
+//!
+//! They work on errors and references to errors, including `&dyn Error`,
+//! and on `Box<dyn Error>` and `Box<dyn Error + Send + Sync>`, which do not
+//! implement `Error` themselves.
+
+use std::any;
+use std::error::Error;
+use std::fmt;
+use std::io;
+use std::iter;
+use std::marker::PhantomData;
+
+use core::*;
+
+/// Values the error matchers can inspect.
+///
+/// `K` is `Unboxed` for types implementing `Error` and `Boxed` for boxed
+/// trait objects and references to them. It keeps the implementations apart
+/// and is inferred from the value being matched.
+pub trait AsError<K> {
+    fn as_error(&self) -> &dyn Error;
+}
+
+/// `AsError` kind of the types implementing `Error`.
+pub enum Unboxed {}
+
+/// `AsError` kind of `Box<dyn Error>`, `Box<dyn Error + Send + Sync>` and
+/// references to them.
+pub enum Boxed {}
+
+impl<E: Error> AsError<Unboxed> for E {
+    fn as_error(&self) -> &dyn Error {
+        self
+    }
+}
+
+impl AsError<Boxed> for Box<dyn Error> {
+    fn as_error(&self) -> &dyn Error {
+        &**self
+    }
+}
+
+impl AsError<Boxed> for Box<dyn Error + Send + Sync> {
+    fn as_error(&self) -> &dyn Error {
+        &**self
+    }
+}
+
+impl<B: AsError<Boxed>> AsError<Boxed> for &B {
+    fn as_error(&self) -> &dyn Error {
+        (**self).as_error()
+    }
+}
+
+pub struct ErrorMessage<M, K> {
+    matcher: M,
+    marker: PhantomData<K>,
+}
+
+impl<M: fmt::Display, K> fmt::Display for ErrorMessage<M, K> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "an error with message {}", self.matcher)
+    }
+}
+
+impl<E, M, K> Matcher<E> for ErrorMessage<M, K>
+where
+    E: AsError<K>,
+    M: for<'a> Matcher<&'a str>,
+{
+    fn matches(&self, actual: E) -> MatchResult {
+        self.matcher
+            .matches(&actual.as_error().to_string())
+            .map_err(|mismatch| mismatch.with_label("message"))
+    }
+
+    fn is_match(&self, actual: E) -> bool {
+        self.matcher.is_match(&actual.as_error().to_string())
+    }
+
+    fn describe_match(&self, actual: E) -> Option<Description> {
+        self.matcher
+            .describe_match(&actual.as_error().to_string())
+            .map(|description| description.with_label("message"))
+    }
+}
+
+/// Matches errors whose `to_string()` matches `matcher`.
+///
+/// ```
+/// # #[macro_use] extern crate hamcrest;
+/// # use hamcrest::prelude::*;
+/// # fn main() {
+/// let error = "x".parse::<i32>().unwrap_err();
+/// assert_that!(error, error_message(matches_regex("invalid digit")));
+/// # }
+/// ```
+pub fn error_message<M, K>(matcher: M) -> ErrorMessage<M, K> {
+    ErrorMessage {
+        matcher,
+        marker: PhantomData,
+    }
+}
+
+pub struct CausedBy<C, M, K> {
+    matcher: M,
+    marker: PhantomData<(C, K)>,
+}
+
+impl<C, M: fmt::Display, K> fmt::Display for CausedBy<C, M, K> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "an error caused by a {} that is {}",
+            any::type_name::<C>(),
+            self.matcher
+        )
+    }
+}
+
+impl<E, C, M, K> Matcher<E> for CausedBy<C, M, K>
+where
+    E: AsError<K>,
+    C: Error + 'static,
+    M: for<'a> Matcher<&'a C>,
+{
+    fn matches(&self, actual: E) -> MatchResult {
+        let mut mismatches = Vec::new();
+        for (depth, cause) in causes(actual.as_error()).enumerate() {
+            if let Some(cause) = cause.downcast_ref::<C>() {
+                match self.matcher.matches(cause) {
+                    Ok(()) => return success(),
+                    Err(mismatch) => {
+                        mismatches.push(mismatch.with_label(format!("cause [{}]", depth)))
+                    }
+                }
+            }
+        }
+
+        if mismatches.is_empty() {
+            let mut description = Description::new()
+                .append_text(format!("had no cause of type {}", any::type_name::<C>()));
+            for (depth, cause) in causes(actual.as_error()).enumerate() {
+                description = description.append_child(
+                    Description::new()
+                        .with_label(format!("cause [{}]", depth))
+                        .append_text(cause.to_string()),
+                );
+            }
+            Err(description)
+        } else {
+            let description = Description::new().append_text(format!(
+                "had no matching cause of type {}:",
+                any::type_name::<C>()
+            ));
+            Err(mismatches.into_iter().fold(description, Description::append_child))
+        }
+    }
+
+    fn is_match(&self, actual: E) -> bool {
+        causes(actual.as_error())
+            .filter_map(|cause| cause.downcast_ref::<C>())
+            .any(|cause| self.matcher.is_match(cause))
+    }
+}
+
+/// Matches errors with a cause, found by following `source()`, of type `C`
+/// that matches `matcher`.
+///
+/// `C` is named with a turbofish, `caused_by::<io::Error, _>(matcher)`, the
+/// second parameter is inferred from the error being matched.
+///
+/// ```
+/// # #[macro_use] extern crate hamcrest;
+/// # use hamcrest::prelude::*;
+/// # use std::{error, fmt, io};
+/// #[derive(Debug)]
+/// struct ConfigError(io::Error);
+///
+/// impl fmt::Display for ConfigError {
+///     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+///         write!(f, "could not read the configuration")
+///     }
+/// }
+///
+/// impl error::Error for ConfigError {
+///     fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+///         Some(&self.0)
+///     }
+/// }
+///
+/// # fn main() {
+/// let error = ConfigError(io::Error::from(io::ErrorKind::NotFound));
+/// assert_that!(&error, caused_by::<io::Error, _>(error_kind(io::ErrorKind::NotFound)));
+/// # }
+/// ```
+pub fn caused_by<C, K>(
+    matcher: impl for<'a> Matcher<&'a C>,
+) -> CausedBy<C, impl for<'a> Matcher<&'a C>, K> {
+    CausedBy {
+        matcher,
+        marker: PhantomData,
+    }
+}
+
+/// The chain of errors returned by `source()`, starting with the source of
+/// `error`.
+fn causes<'a>(error: &'a dyn Error) -> impl Iterator<Item = &'a (dyn Error + 'static)> {
+    let mut cause = error.source();
+    iter::from_fn(move || {
+        let current = cause?;
+        cause = current.source();
+        Some(current)
+    })
+}
+
+pub struct ErrorKind {
+    kind: io::ErrorKind,
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "an io::Error of kind {:?}", self.kind)
+    }
+}
+
+impl<'a> Matcher<&'a io::Error> for ErrorKind {
+    fn matches(&self, actual: &'a io::Error) -> MatchResult {
+        if actual.kind() == self.kind {
+            success()
+        } else {
+            Err(Description::new().append_text(format!(
+                "was an error of kind {:?}: {}",
+                actual.kind(),
+                actual
+            )))
+        }
+    }
+
+    fn is_match(&self, actual: &'a io::Error) -> bool {
+        actual.kind() == self.kind
+    }
+
+    fn describe_match(&self, actual: &'a io::Error) -> Option<Description> {
+        if actual.kind() == self.kind {
+            Some(Description::new().append_text(format!(
+                "was an error of kind {:?}: {}",
+                actual.kind(),
+                actual
+            )))
+        } else {
+            None
+        }
+    }
+}
+
+impl Matcher<io::Error> for ErrorKind {
+    fn matches(&self, actual: io::Error) -> MatchResult {
+        self.matches(&actual)
+    }
+
+    fn is_match(&self, actual: io::Error) -> bool {
+        self.is_match(&actual)
+    }
+
+    fn describe_match(&self, actual: io::Error) -> Option<Description> {
+        self.describe_match(&actual)
+    }
+}
+
+/// Matches `io::Error`s of the given kind.
+pub fn error_kind(kind: io::ErrorKind) -> ErrorKind {
+    ErrorKind { kind }
+}
@@ -58,15 +58,45 @@ impl<'a> Matcher<&'a PathBuf> for ExistingPath {
     fn matches(&self, actual: &'a PathBuf) -> MatchResult {
         self.matches(&**actual)
     }
+
+    fn is_match(&self, actual: &'a PathBuf) -> bool {
+        self.is_match(&**actual)
+    }
+
+    fn describe_match(&self, actual: &'a PathBuf) -> Option<Description> {
+        self.describe_match(&**actual)
+    }
 }
 
-impl<'a> Matcher<&'a Path> for ExistingPath {
+impl Matcher<&Path> for ExistingPath {
     fn matches(&self, actual: &Path) -> MatchResult {
         expect(
             fs::metadata(actual).is_ok(),
             format!("{} was missing", actual.display()),
         ).and(self.match_path_type(actual))
     }
+
+    fn is_match(&self, actual: &Path) -> bool {
+        fs::metadata(actual)
+            .map(|m| match self.path_type {
+                PathType::AnyType => true,
+                PathType::File => m.is_file(),
+                PathType::Dir => m.is_dir(),
+            })
+            .unwrap_or(false)
+    }
+
+    fn describe_match(&self, actual: &Path) -> Option<Description> {
+        if !self.is_match(actual) {
+            return None;
+        }
+        let description = match self.path_type {
+            PathType::AnyType => format!("`{}` existed", actual.display()),
+            PathType::File => format!("`{}` was a file", actual.display()),
+            PathType::Dir => format!("`{}` was a dir", actual.display()),
+        };
+        Some(description.into())
+    }
 }
 
 pub fn existing_path() -> ExistingPath {
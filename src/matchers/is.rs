@@ -27,11 +27,27 @@ impl<T, M: Matcher<T>> Matcher<T> for Is<T, M> {
     fn matches(&self, actual: T) -> MatchResult {
         self.matcher.matches(actual)
     }
+
+    fn is_match(&self, actual: T) -> bool {
+        self.matcher.is_match(actual)
+    }
+
+    fn describe_to(&self, description: Description) -> Description {
+        self.matcher.describe_to(description)
+    }
+
+    fn describe_mismatch(&self, actual: T) -> Description {
+        self.matcher.describe_mismatch(actual)
+    }
+
+    fn describe_match(&self, actual: T) -> Option<Description> {
+        self.matcher.describe_match(actual)
+    }
 }
 
 pub fn is<T, M: Matcher<T>>(matcher: M) -> Is<T, M> {
     Is {
-        matcher: matcher,
+        matcher,
         marker: PhantomData,
     }
 }
@@ -49,16 +65,24 @@ impl<T, M: Matcher<T>> fmt::Display for IsNot<T, M> {
 
 impl<T, M: Matcher<T>> Matcher<T> for IsNot<T, M> {
     fn matches(&self, actual: T) -> MatchResult {
-        match self.matcher.matches(actual) {
-            Ok(_) => Err("matched".to_string()),
-            Err(_) => Ok(()),
+        match self.matcher.describe_match(actual) {
+            Some(description) => Err(description),
+            None => success(),
         }
     }
+
+    fn is_match(&self, actual: T) -> bool {
+        !self.matcher.is_match(actual)
+    }
+
+    fn describe_match(&self, actual: T) -> Option<Description> {
+        self.matcher.matches(actual).err()
+    }
 }
 
 pub fn is_not<T, M: Matcher<T>>(matcher: M) -> IsNot<T, M> {
     IsNot {
-        matcher: matcher,
+        matcher,
         marker: PhantomData,
     }
 }
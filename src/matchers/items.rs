@@ -0,0 +1,262 @@
+// Copyright 2026 The hamcrest-rust Developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Matchers for the elements of collections, the counterparts of
+//! `vecs::contains` that check each element with a matcher instead of
+//! comparing it for equality.
+//!
+//! Like `contains` they work on references to any collection that can be
+//! iterated by reference. The element matchers are given as a `MatcherList`
+//! of `Matcher<&T>`s: a tuple, array or `Vec`.
+
+use std::fmt;
+use std::marker::PhantomData;
+
+use core::*;
+use matchers::matcher_list::{fmt_list, MatcherList};
+
+/// Which elements the matchers of `HasItems` must account for.
+#[derive(Clone, Copy, PartialEq)]
+enum Mode {
+    /// Every matcher matches a different element.
+    Some,
+    /// Every matcher matches a different element and every element is
+    /// matched.
+    AnyOrder,
+    /// The n-th matcher matches the n-th element and every element is
+    /// matched.
+    InOrder,
+}
+
+pub struct HasItems<T, M> {
+    matchers: M,
+    mode: Mode,
+    marker: PhantomData<T>,
+}
+
+impl<'a, T: 'a, M: MatcherList<&'a T>> fmt::Display for HasItems<T, M> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self.mode {
+            Mode::Some => "having items",
+            Mode::AnyOrder => "containing in any order",
+            Mode::InOrder => "containing exactly",
+        };
+        fmt_list(name, &self.matchers, f)
+    }
+}
+
+impl<'a, T, C, M> Matcher<&'a C> for HasItems<T, M>
+where
+    T: fmt::Debug + 'a,
+    C: fmt::Debug + ?Sized,
+    &'a C: IntoIterator<Item = &'a T>,
+    M: MatcherList<&'a T>,
+{
+    fn matches(&self, actual: &'a C) -> MatchResult {
+        let elements: Vec<&T> = actual.into_iter().collect();
+        let problems = match self.mode {
+            Mode::InOrder => self.positional_mismatches(&elements),
+            _ => self.assignment_mismatches(&elements),
+        };
+
+        if problems.is_empty() {
+            success()
+        } else {
+            let description = Description::new()
+                .append_text("was ")
+                .append_value(actual)
+                .append_text(", which did not match:");
+            Err(problems.into_iter().fold(description, Description::append_child))
+        }
+    }
+
+    fn is_match(&self, actual: &'a C) -> bool {
+        let elements: Vec<&T> = actual.into_iter().collect();
+        match self.mode {
+            Mode::InOrder => {
+                elements.len() == self.matchers.len() &&
+                    elements
+                        .iter()
+                        .enumerate()
+                        .all(|(i, element)| self.matchers.get(i).is_match(element))
+            }
+            Mode::AnyOrder if elements.len() != self.matchers.len() => false,
+            _ => {
+                let assignment = assign(&self.match_table(&elements), elements.len());
+                assignment.iter().all(Option::is_some)
+            }
+        }
+    }
+}
+
+impl<'a, T: fmt::Debug + 'a, M: MatcherList<&'a T>> HasItems<T, M> {
+    /// `table[i][j]` is whether matcher `i` matches element `j`.
+    fn match_table(&self, elements: &[&'a T]) -> Vec<Vec<bool>> {
+        (0..self.matchers.len())
+            .map(|i| {
+                let matcher = self.matchers.get(i);
+                elements.iter().map(|element| matcher.is_match(element)).collect()
+            })
+            .collect()
+    }
+
+    fn assignment_mismatches(&self, elements: &[&'a T]) -> Vec<Description> {
+        let table = self.match_table(elements);
+        let assignment = assign(&table, elements.len());
+        let mut problems = Vec::new();
+
+        for (i, assigned) in assignment.iter().enumerate() {
+            if assigned.is_some() {
+                continue;
+            }
+            let matched: Vec<usize> = (0..elements.len()).filter(|&j| table[i][j]).collect();
+            let problem = if matched.is_empty() {
+                Description::new().append_text("matched no element")
+            } else {
+                Description::new().append_text(format!(
+                    "matched only elements {:?}, which other matchers needed",
+                    matched
+                ))
+            };
+            problems.push(problem.with_label(format!("[{}] {}", i, self.matchers.get(i))));
+        }
+
+        if self.mode == Mode::AnyOrder {
+            for (j, element) in elements.iter().enumerate() {
+                if !assignment.contains(&Some(j)) {
+                    problems.push(unexpected(j, element));
+                }
+            }
+        }
+
+        problems
+    }
+
+    fn positional_mismatches(&self, elements: &[&'a T]) -> Vec<Description> {
+        let mut problems = Vec::new();
+        for i in 0..self.matchers.len() {
+            let matcher = self.matchers.get(i);
+            let problem = match elements.get(i) {
+                Some(element) => matcher.matches(element).err(),
+                None => Some(Description::new().append_text("was missing")),
+            };
+            if let Some(problem) = problem {
+                problems.push(problem.with_label(format!("[{}] {}", i, matcher)));
+            }
+        }
+        for (j, element) in elements.iter().enumerate().skip(self.matchers.len()) {
+            problems.push(unexpected(j, element));
+        }
+        problems
+    }
+}
+
+fn unexpected<T: fmt::Debug>(index: usize, element: &T) -> Description {
+    Description::new()
+        .with_label(format!("element [{}]", index))
+        .append_value(element)
+        .append_text(" was not expected")
+}
+
+/// Assigns matchers to different elements, as many as possible, using
+/// `table[i][j]` to tell whether matcher `i` matches element `j`.
+///
+/// Returns the element assigned to each matcher. This is a maximum bipartite
+/// matching found with augmenting paths, so a matcher that accepts several
+/// elements does not take the only element another matcher accepts.
+fn assign(table: &[Vec<bool>], elements: usize) -> Vec<Option<usize>> {
+    let mut owners = vec![None; elements];
+    for matcher in 0..table.len() {
+        let mut visited = vec![false; elements];
+        augment(matcher, table, &mut visited, &mut owners);
+    }
+
+    let mut assignment = vec![None; table.len()];
+    for (element, owner) in owners.iter().enumerate() {
+        if let Some(matcher) = *owner {
+            assignment[matcher] = Some(element);
+        }
+    }
+    assignment
+}
+
+/// Tries to assign `matcher` an element, moving other matchers to other
+/// elements if needed.
+fn augment(
+    matcher: usize,
+    table: &[Vec<bool>],
+    visited: &mut [bool],
+    owners: &mut [Option<usize>],
+) -> bool {
+    for element in 0..owners.len() {
+        if !table[matcher][element] || visited[element] {
+            continue;
+        }
+        visited[element] = true;
+        let free = match owners[element] {
+            Some(owner) => augment(owner, table, visited, owners),
+            None => true,
+        };
+        if free {
+            owners[element] = Some(matcher);
+            return true;
+        }
+    }
+    false
+}
+
+fn has_items_with<T, M>(matchers: M, mode: Mode) -> HasItems<T, M> {
+    HasItems {
+        matchers,
+        mode,
+        marker: PhantomData,
+    }
+}
+
+/// Matches collections with a different element for each of `matchers`,
+/// other elements are allowed.
+///
+/// ```
+/// # #[macro_use] extern crate hamcrest;
+/// # use hamcrest::prelude::*;
+/// # fn main() {
+/// assert_that!(&vec![1, 5, 10], has_items((greater_than(&2), less_than(&3))));
+/// # }
+/// ```
+pub fn has_items<T, M>(matchers: M) -> HasItems<T, M> {
+    has_items_with(matchers, Mode::Some)
+}
+
+/// Matches collections with exactly one element for each of `matchers`, in
+/// any order.
+pub fn contains_in_any_order<T, M>(matchers: M) -> HasItems<T, M> {
+    has_items_with(matchers, Mode::AnyOrder)
+}
+
+/// Matches collections whose n-th element matches the n-th of `matchers`,
+/// with no further elements.
+pub fn contains_exactly_matching<T, M>(matchers: M) -> HasItems<T, M> {
+    has_items_with(matchers, Mode::InOrder)
+}
+
+#[macro_export]
+macro_rules! has_items {
+    ($( $arg:expr ),+ $(,)*) => ($crate::matchers::items::has_items(($( $arg, )+)))
+}
+
+#[macro_export]
+macro_rules! contains_in_any_order {
+    ($( $arg:expr ),+ $(,)*) => ($crate::matchers::items::contains_in_any_order(($( $arg, )+)))
+}
+
+#[macro_export]
+macro_rules! contains_exactly_matching {
+    ($( $arg:expr ),+ $(,)*) => (
+        $crate::matchers::items::contains_exactly_matching(($( $arg, )+))
+    )
+}
@@ -0,0 +1,87 @@
+// Copyright 2026 The hamcrest-rust Developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::fmt;
+
+use core::*;
+
+/// A list of matchers for the same type, as taken by `all_of` and `any_of`.
+///
+/// It is implemented for tuples of one to six matchers, which may all be of
+/// different types, and for `Vec`s and arrays of matchers, which can be of any
+/// length. Use `MatcherExt::boxed` to put different matchers in a `Vec`.
+pub trait MatcherList<T> {
+    fn len(&self) -> usize;
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the matcher at `index`, panics if it is out of bounds.
+    fn get(&self, index: usize) -> &dyn Matcher<T>;
+}
+
+/// Writes `name (m0, m1, ...)`.
+pub(crate) fn fmt_list<T, L: MatcherList<T> + ?Sized>(
+    name: &str,
+    matchers: &L,
+    f: &mut fmt::Formatter,
+) -> fmt::Result {
+    write!(f, "{} (", name)?;
+    for i in 0..matchers.len() {
+        if i != 0 {
+            write!(f, ", ")?;
+        }
+        write!(f, "{}", matchers.get(i))?;
+    }
+    write!(f, ")")
+}
+
+macro_rules! tuple_matcher_list {
+    ($len:expr; $($m:ident: $idx:tt),+) => {
+        impl<T, $($m: Matcher<T>),+> MatcherList<T> for ($($m,)+) {
+            fn len(&self) -> usize {
+                $len
+            }
+
+            fn get(&self, index: usize) -> &dyn Matcher<T> {
+                match index {
+                    $($idx => &self.$idx,)+
+                    _ => panic!("index out of bounds: the len is {} but the index is {}", $len, index),
+                }
+            }
+        }
+    }
+}
+
+tuple_matcher_list!(1; M0: 0);
+tuple_matcher_list!(2; M0: 0, M1: 1);
+tuple_matcher_list!(3; M0: 0, M1: 1, M2: 2);
+tuple_matcher_list!(4; M0: 0, M1: 1, M2: 2, M3: 3);
+tuple_matcher_list!(5; M0: 0, M1: 1, M2: 2, M3: 3, M4: 4);
+tuple_matcher_list!(6; M0: 0, M1: 1, M2: 2, M3: 3, M4: 4, M5: 5);
+
+impl<T, M: Matcher<T>> MatcherList<T> for Vec<M> {
+    fn len(&self) -> usize {
+        self[..].len()
+    }
+
+    fn get(&self, index: usize) -> &dyn Matcher<T> {
+        &self[index]
+    }
+}
+
+impl<T, M: Matcher<T>, const N: usize> MatcherList<T> for [M; N] {
+    fn len(&self) -> usize {
+        N
+    }
+
+    fn get(&self, index: usize) -> &dyn Matcher<T> {
+        &self[index]
+    }
+}
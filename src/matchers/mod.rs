@@ -8,11 +8,19 @@
 
 pub mod close_to;
 pub mod compared_to;
+pub mod described_as;
 pub mod equal_to;
+pub mod error;
 pub mod existing_path;
 pub mod is;
+pub mod items;
+pub mod matcher_list;
 pub mod none;
+pub mod panics;
 pub mod regex;
+pub mod result;
+pub mod some;
+pub mod strings;
 pub mod vecs;
 pub mod anything;
 pub mod type_of;
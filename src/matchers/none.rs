@@ -27,10 +27,26 @@ impl<T> fmt::Display for IsNone<T> {
 impl<T: fmt::Debug> Matcher<Option<T>> for IsNone<T> {
     fn matches(&self, actual: Option<T>) -> MatchResult {
         match actual {
-            Some(s) => Err(format!("was Some({:?})", s)),
+            Some(s) => Err(
+                Description::new()
+                    .append_text("was ")
+                    .append_value(&Some(s)),
+            ),
             None => success(),
         }
     }
+
+    fn is_match(&self, actual: Option<T>) -> bool {
+        actual.is_none()
+    }
+
+    fn describe_match(&self, actual: Option<T>) -> Option<Description> {
+        if actual.is_none() {
+            Some(Description::new().append_text("was None"))
+        } else {
+            None
+        }
+    }
 }
 
 pub fn none<T>() -> IsNone<T> {
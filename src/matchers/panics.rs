@@ -0,0 +1,124 @@
+// Copyright 2026 The hamcrest-rust Developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Matchers for closures that are expected to panic.
+
+use std::any::Any;
+use std::cell::Cell;
+use std::fmt;
+use std::panic::{self, UnwindSafe};
+use std::sync::Once;
+
+use core::*;
+
+thread_local! {
+    static SILENCED: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Runs `f`, catching its panic without printing it.
+///
+/// The panic hook is shared by all threads, so it is replaced once by a hook
+/// that stays quiet only on threads running this function.
+fn catch_panic<F: FnOnce() + UnwindSafe>(f: F) -> Option<Box<dyn Any + Send>> {
+    static INSTALL_HOOK: Once = Once::new();
+    INSTALL_HOOK.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if !SILENCED.with(Cell::get) {
+                previous(info);
+            }
+        }));
+    });
+
+    let silenced = SILENCED.with(|silenced| silenced.replace(true));
+    let result = panic::catch_unwind(f);
+    SILENCED.with(|flag| flag.set(silenced));
+    result.err()
+}
+
+fn payload_message(payload: &(dyn Any + Send)) -> Option<&str> {
+    payload
+        .downcast_ref::<&str>()
+        .cloned()
+        .or_else(|| payload.downcast_ref::<String>().map(|s| &s[..]))
+}
+
+pub struct Panics;
+
+impl fmt::Display for Panics {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a closure that panics")
+    }
+}
+
+impl<F: FnOnce() + UnwindSafe> Matcher<F> for Panics {
+    fn matches(&self, actual: F) -> MatchResult {
+        match catch_panic(actual) {
+            Some(_) => success(),
+            None => Err(Description::new().append_text("did not panic")),
+        }
+    }
+}
+
+/// Matches closures that panic when called.
+///
+/// The panic message is not printed.
+///
+/// ```
+/// # #[macro_use] extern crate hamcrest;
+/// # use hamcrest::prelude::*;
+/// # fn main() {
+/// assert_that!(|| panic!("boom"), panics());
+/// assert_that!(|| (), not(panics()));
+/// # }
+/// ```
+pub fn panics() -> Panics {
+    Panics
+}
+
+pub struct PanicsWith<M> {
+    matcher: M,
+}
+
+impl<M: fmt::Display> fmt::Display for PanicsWith<M> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a closure that panics with message {}", self.matcher)
+    }
+}
+
+impl<F: FnOnce() + UnwindSafe, M: for<'a> Matcher<&'a str>> Matcher<F> for PanicsWith<M> {
+    fn matches(&self, actual: F) -> MatchResult {
+        let payload = match catch_panic(actual) {
+            Some(payload) => payload,
+            None => return Err(Description::new().append_text("did not panic")),
+        };
+        match payload_message(&*payload) {
+            Some(message) => self.matcher
+                .matches(message)
+                .map_err(|mismatch| mismatch.with_label("panic message")),
+            None => Err(
+                Description::new().append_text("panicked with a payload that is not a string"),
+            ),
+        }
+    }
+}
+
+/// Matches closures that panic with a `&str` or `String` message matching
+/// `matcher`.
+///
+/// ```
+/// # #[macro_use] extern crate hamcrest;
+/// # use hamcrest::prelude::*;
+/// # fn main() {
+/// let divide = |a: i32, b: i32| move || { a / b; };
+/// assert_that!(divide(1, 0), panics_with(matches_regex("divide by zero")));
+/// # }
+/// ```
+pub fn panics_with<M>(matcher: M) -> PanicsWith<M> {
+    PanicsWith { matcher }
+}
@@ -6,31 +6,465 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use regex::Regex;
+use regex::{bytes, Error, Regex, RegexSet};
+use std::borrow::Cow;
+use std::ffi::OsStr;
 use std::fmt;
 
 use core::*;
+use matchers::matcher_list::{fmt_list, MatcherList};
 
+/// Matches text with a `regex::bytes::Regex`, so that patterns for binary
+/// data work on byte slices.
 pub struct MatchesRegex {
-    regex: Regex,
+    regex: bytes::Regex,
+    pattern: String,
+    full: bool,
+}
+
+impl MatchesRegex {
+    /// Like `matches_regex`, but returns the error of an invalid pattern
+    /// instead of panicking.
+    pub fn new(pattern: &str) -> Result<MatchesRegex, Error> {
+        Ok(MatchesRegex {
+            regex: bytes::Regex::new(pattern)?,
+            pattern: pattern.to_string(),
+            full: false,
+        })
+    }
+
+    /// Like `fully_matches_regex`, but returns the error of an invalid
+    /// pattern instead of panicking.
+    pub fn full(pattern: &str) -> Result<MatchesRegex, Error> {
+        Ok(MatchesRegex {
+            regex: bytes::Regex::new(&format!(r"\A(?:{})\z", pattern))?,
+            pattern: pattern.to_string(),
+            full: true,
+        })
+    }
 }
 
 impl fmt::Display for MatchesRegex {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        self.regex.fmt(f)
+        if self.full {
+            write!(f, "fully matching {}", self.pattern)
+        } else {
+            f.write_str(&self.pattern)
+        }
     }
 }
 
 impl<'a> Matcher<&'a str> for MatchesRegex {
     fn matches(&self, actual: &'a str) -> MatchResult {
+        if self.regex.is_match(actual.as_bytes()) {
+            success()
+        } else {
+            Err(Description::new().append_text("was ").append_value(actual))
+        }
+    }
+
+    fn is_match(&self, actual: &'a str) -> bool {
+        self.regex.is_match(actual.as_bytes())
+    }
+
+    fn describe_match(&self, actual: &'a str) -> Option<Description> {
+        if self.regex.is_match(actual.as_bytes()) {
+            let was = Description::new().append_text("was ").append_value(actual);
+            Some(self.describe_text_match(was))
+        } else {
+            None
+        }
+    }
+}
+
+impl MatchesRegex {
+    fn describe_text_match(&self, was: Description) -> Description {
+        let verb = if self.full { "fully matches" } else { "matches" };
+        was.append_text(format!(", which {} {}", verb, self.pattern))
+    }
+}
+
+/// Implements `Matcher<$t>` for a matcher of `&str`, for types that can be
+/// sliced into a `str`.
+macro_rules! str_like_matcher {
+    ($matcher:ty; $($lt:lifetime)*; $t:ty) => {
+        impl<$($lt)*> Matcher<$t> for $matcher {
+            fn matches(&self, actual: $t) -> MatchResult {
+                self.matches(&actual[..])
+            }
+
+            fn is_match(&self, actual: $t) -> bool {
+                self.is_match(&actual[..])
+            }
+
+            fn describe_match(&self, actual: $t) -> Option<Description> {
+                self.describe_match(&actual[..])
+            }
+        }
+    };
+}
+
+str_like_matcher!(MatchesRegex; 'a; &'a String);
+str_like_matcher!(MatchesRegex; ; String);
+str_like_matcher!(MatchesRegex; 'a; Cow<'a, str>);
+
+impl<'a> Matcher<&'a OsStr> for MatchesRegex {
+    fn matches(&self, actual: &'a OsStr) -> MatchResult {
+        match actual.to_str() {
+            Some(actual) => self.matches(actual),
+            None => Err(
+                Description::new()
+                    .append_text("was ")
+                    .append_value(actual)
+                    .append_text(", which is not valid unicode"),
+            ),
+        }
+    }
+
+    fn is_match(&self, actual: &'a OsStr) -> bool {
+        actual.to_str().is_some_and(|actual| self.is_match(actual))
+    }
+
+    fn describe_match(&self, actual: &'a OsStr) -> Option<Description> {
+        actual.to_str().and_then(|actual| self.describe_match(actual))
+    }
+}
+
+/// Describes bytes as a byte string literal.
+fn was_bytes(actual: &[u8]) -> Description {
+    Description::new().append_text("was ").append_fragment(Fragment::Value(format!(
+        "b\"{}\"",
+        actual.escape_ascii()
+    )))
+}
+
+impl<'a> Matcher<&'a [u8]> for MatchesRegex {
+    fn matches(&self, actual: &'a [u8]) -> MatchResult {
         if self.regex.is_match(actual) {
             success()
         } else {
-            Err(format!("was {:?}", actual))
+            Err(was_bytes(actual))
+        }
+    }
+
+    fn is_match(&self, actual: &'a [u8]) -> bool {
+        self.regex.is_match(actual)
+    }
+
+    fn describe_match(&self, actual: &'a [u8]) -> Option<Description> {
+        if self.regex.is_match(actual) {
+            Some(self.describe_text_match(was_bytes(actual)))
+        } else {
+            None
         }
     }
 }
 
+/// Matches strings that contain a match of the regular expression `regex`.
+///
+/// Panics if `regex` is invalid, see `MatchesRegex::new`.
 pub fn matches_regex(regex: &str) -> MatchesRegex {
-    MatchesRegex { regex: Regex::new(regex).unwrap() }
+    MatchesRegex::new(regex).unwrap()
+}
+
+/// Matches strings that match the regular expression `regex` from start to
+/// end.
+///
+/// Panics if `regex` is invalid, see `MatchesRegex::full`.
+pub fn fully_matches_regex(regex: &str) -> MatchesRegex {
+    MatchesRegex::full(regex).unwrap()
+}
+
+pub struct Captures<M> {
+    regex: Regex,
+    matchers: M,
+}
+
+impl<M> Captures<M> {
+    /// Like `captures`, but returns the error of an invalid pattern instead
+    /// of panicking.
+    pub fn new(regex: &str, matchers: M) -> Result<Captures<M>, Error> {
+        Ok(Captures {
+            regex: Regex::new(regex)?,
+            matchers,
+        })
+    }
+}
+
+impl<'a, M: MatcherList<&'a str>> fmt::Display for Captures<M> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "matching {} with ", self.regex)?;
+        fmt_list("groups", &self.matchers, f)
+    }
+}
+
+impl<'a, M: MatcherList<&'a str>> Matcher<&'a str> for Captures<M> {
+    fn matches(&self, actual: &'a str) -> MatchResult {
+        let captures = match self.regex.captures(actual) {
+            Some(captures) => captures,
+            None => return Err(Description::new().append_text("was ").append_value(actual)),
+        };
+
+        let mut description = Description::new()
+            .append_text("was ")
+            .append_value(actual)
+            .append_text(", with groups that did not match:");
+        let mut failed = false;
+        for i in 0..self.matchers.len() {
+            let group = i + 1;
+            let mismatch = match captures.get(group) {
+                Some(capture) => self.matchers.get(i).matches(capture.as_str()).err(),
+                None => Some(Description::new().append_text("was not captured")),
+            };
+            if let Some(mismatch) = mismatch {
+                description =
+                    description.append_child(mismatch.with_label(format!("group {}", group)));
+                failed = true;
+            }
+        }
+
+        if failed { Err(description) } else { success() }
+    }
+
+    fn is_match(&self, actual: &'a str) -> bool {
+        self.regex.captures(actual).is_some_and(|captures| {
+            (0..self.matchers.len()).all(|i| {
+                captures
+                    .get(i + 1)
+                    .is_some_and(|capture| self.matchers.get(i).is_match(capture.as_str()))
+            })
+        })
+    }
+}
+
+/// Matches strings that match the regular expression `regex` with numbered
+/// capture groups matching `matchers`, the first matcher is applied to group
+/// 1.
+///
+/// `matchers` is a tuple, array or `Vec` of matchers, like for `all_of`.
+/// Panics if `regex` is invalid, see `Captures::new`.
+///
+/// ```
+/// # #[macro_use] extern crate hamcrest;
+/// # use hamcrest::prelude::*;
+/// # fn main() {
+/// assert_that!(
+///     "version 1.42",
+///     captures(r"(\d+)\.(\d+)", (equal_to("1"), fully_matches_regex(r"\d{2}")))
+/// );
+/// # }
+/// ```
+pub fn captures<M>(regex: &str, matchers: M) -> Captures<M> {
+    Captures::new(regex, matchers).unwrap()
+}
+
+pub struct NamedCapture<M> {
+    regex: Regex,
+    name: String,
+    matcher: M,
+}
+
+impl<M> NamedCapture<M> {
+    /// Like `named_capture`, but returns the error of an invalid pattern
+    /// instead of panicking.
+    pub fn new<S>(regex: &str, name: S, matcher: M) -> Result<NamedCapture<M>, Error>
+    where
+        S: Into<String>,
+    {
+        Ok(NamedCapture {
+            regex: Regex::new(regex)?,
+            name: name.into(),
+            matcher,
+        })
+    }
+}
+
+impl<M: fmt::Display> fmt::Display for NamedCapture<M> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "matching {} with group `{}` {}", self.regex, self.name, self.matcher)
+    }
+}
+
+impl<'a, M: Matcher<&'a str>> Matcher<&'a str> for NamedCapture<M> {
+    fn matches(&self, actual: &'a str) -> MatchResult {
+        let captures = match self.regex.captures(actual) {
+            Some(captures) => captures,
+            None => return Err(Description::new().append_text("was ").append_value(actual)),
+        };
+        let mismatch = match captures.name(&self.name) {
+            Some(capture) => match self.matcher.matches(capture.as_str()) {
+                Ok(()) => return success(),
+                Err(mismatch) => mismatch,
+            },
+            None => Description::new().append_text("was not captured"),
+        };
+        Err(Description::new()
+            .append_text("was ")
+            .append_value(actual)
+            .append_text(", with a group that did not match:")
+            .append_child(mismatch.with_label(format!("group `{}`", self.name))))
+    }
+
+    fn is_match(&self, actual: &'a str) -> bool {
+        self.regex
+            .captures(actual)
+            .and_then(|captures| captures.name(&self.name))
+            .is_some_and(|capture| self.matcher.is_match(capture.as_str()))
+    }
+}
+
+/// Matches strings that match the regular expression `regex` with the
+/// capture group `name` matching `matcher`.
+///
+/// Panics if `regex` is invalid, see `NamedCapture::new`.
+///
+/// ```
+/// # #[macro_use] extern crate hamcrest;
+/// # use hamcrest::prelude::*;
+/// # fn main() {
+/// assert_that!(
+///     "2024-05-17",
+///     named_capture(r"(?P<year>\d{4})-\d{2}-\d{2}", "year", equal_to("2024"))
+/// );
+/// # }
+/// ```
+pub fn named_capture<S: Into<String>, M>(regex: &str, name: S, matcher: M) -> NamedCapture<M> {
+    NamedCapture::new(regex, name, matcher).unwrap()
+}
+
+pub struct MatchesPatterns {
+    set: RegexSet,
+    patterns: Vec<String>,
+    all: bool,
+}
+
+impl MatchesPatterns {
+    /// Like `matches_all_patterns`, but returns the error of an invalid
+    /// pattern instead of panicking.
+    pub fn all<I>(patterns: I) -> Result<MatchesPatterns, Error>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        MatchesPatterns::new(patterns, true)
+    }
+
+    /// Like `matches_any_pattern`, but returns the error of an invalid
+    /// pattern instead of panicking.
+    pub fn any<I>(patterns: I) -> Result<MatchesPatterns, Error>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        MatchesPatterns::new(patterns, false)
+    }
+
+    fn new<I>(patterns: I, all: bool) -> Result<MatchesPatterns, Error>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let patterns: Vec<String> = patterns
+            .into_iter()
+            .map(|pattern| pattern.as_ref().to_string())
+            .collect();
+        Ok(MatchesPatterns {
+            set: RegexSet::new(&patterns)?,
+            patterns,
+            all,
+        })
+    }
+
+    fn is_match_str(&self, actual: &str) -> bool {
+        let matches = self.set.matches(actual);
+        if self.all {
+            matches.iter().count() == self.patterns.len()
+        } else {
+            matches.matched_any()
+        }
+    }
+
+    /// Lists which patterns matched `actual` and which did not.
+    fn describe_patterns(&self, actual: &str) -> Description {
+        let matches = self.set.matches(actual);
+        let mut description = Description::new()
+            .append_text("was ")
+            .append_value(actual)
+            .append_text(format!(
+                ", which matched {} of {} patterns:",
+                matches.iter().count(),
+                self.patterns.len()
+            ));
+        for (i, pattern) in self.patterns.iter().enumerate() {
+            let label = if matches.matched(i) { "matched" } else { "did not match" };
+            description = description
+                .append_child(Description::new().with_label(label).append_text(&pattern[..]));
+        }
+        description
+    }
+}
+
+impl fmt::Display for MatchesPatterns {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let quantifier = if self.all { "all" } else { "any" };
+        write!(f, "matching {} of the patterns {:?}", quantifier, self.patterns)
+    }
+}
+
+impl<'a> Matcher<&'a str> for MatchesPatterns {
+    fn matches(&self, actual: &'a str) -> MatchResult {
+        if self.is_match_str(actual) {
+            success()
+        } else {
+            Err(self.describe_patterns(actual))
+        }
+    }
+
+    fn is_match(&self, actual: &'a str) -> bool {
+        self.is_match_str(actual)
+    }
+
+    fn describe_match(&self, actual: &'a str) -> Option<Description> {
+        if self.is_match_str(actual) {
+            Some(self.describe_patterns(actual))
+        } else {
+            None
+        }
+    }
+}
+
+str_like_matcher!(MatchesPatterns; 'a; &'a String);
+str_like_matcher!(MatchesPatterns; ; String);
+str_like_matcher!(MatchesPatterns; 'a; Cow<'a, str>);
+
+/// Matches strings that contain a match of every one of `patterns`.
+///
+/// Failures list which patterns matched and which did not. Panics if a
+/// pattern is invalid, see `MatchesPatterns::all`.
+///
+/// ```
+/// # #[macro_use] extern crate hamcrest;
+/// # use hamcrest::prelude::*;
+/// # fn main() {
+/// let line = "2024-05-17 12:00:01 ERROR connection reset";
+/// assert_that!(line, matches_all_patterns(&[r"^\d{4}-\d{2}-\d{2}", "ERROR", "reset$"]));
+/// # }
+/// ```
+pub fn matches_all_patterns<I>(patterns: I) -> MatchesPatterns
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    MatchesPatterns::all(patterns).unwrap()
+}
+
+/// Matches strings that contain a match of at least one of `patterns`.
+///
+/// Panics if a pattern is invalid, see `MatchesPatterns::any`.
+pub fn matches_any_pattern<I>(patterns: I) -> MatchesPatterns
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    MatchesPatterns::any(patterns).unwrap()
 }
@@ -0,0 +1,132 @@
+// Copyright 2026 The hamcrest-rust Developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::fmt;
+
+use core::*;
+use matchers::anything::{anything, Anything};
+
+pub struct IsOk<M> {
+    matcher: M,
+}
+
+impl<M: fmt::Display> fmt::Display for IsOk<M> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Ok({})", self.matcher)
+    }
+}
+
+impl<T, E: fmt::Debug, M: Matcher<T>> Matcher<Result<T, E>> for IsOk<M> {
+    fn matches(&self, actual: Result<T, E>) -> MatchResult {
+        match actual {
+            Ok(value) => self.matcher
+                .matches(value)
+                .map_err(|mismatch| in_variant("Ok(...)", mismatch)),
+            Err(error) => Err(Description::new().append_text("was ").append_value(
+                &Err::<(), E>(error),
+            )),
+        }
+    }
+
+    fn is_match(&self, actual: Result<T, E>) -> bool {
+        actual.is_ok_and(|value| self.matcher.is_match(value))
+    }
+
+    fn describe_match(&self, actual: Result<T, E>) -> Option<Description> {
+        actual
+            .ok()
+            .and_then(|value| self.matcher.describe_match(value))
+            .map(|description| in_variant("Ok(...)", description))
+    }
+}
+
+impl<'a, T, E: fmt::Debug, M: Matcher<&'a T>> Matcher<&'a Result<T, E>> for IsOk<M> {
+    fn matches(&self, actual: &'a Result<T, E>) -> MatchResult {
+        self.matches(actual.as_ref())
+    }
+
+    fn is_match(&self, actual: &'a Result<T, E>) -> bool {
+        self.is_match(actual.as_ref())
+    }
+
+    fn describe_match(&self, actual: &'a Result<T, E>) -> Option<Description> {
+        self.describe_match(actual.as_ref())
+    }
+}
+
+pub struct IsErr<M> {
+    matcher: M,
+}
+
+impl<M: fmt::Display> fmt::Display for IsErr<M> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Err({})", self.matcher)
+    }
+}
+
+impl<T: fmt::Debug, E, M: Matcher<E>> Matcher<Result<T, E>> for IsErr<M> {
+    fn matches(&self, actual: Result<T, E>) -> MatchResult {
+        match actual {
+            Ok(value) => Err(Description::new().append_text("was ").append_value(
+                &Ok::<T, ()>(value),
+            )),
+            Err(error) => self.matcher
+                .matches(error)
+                .map_err(|mismatch| in_variant("Err(...)", mismatch)),
+        }
+    }
+
+    fn is_match(&self, actual: Result<T, E>) -> bool {
+        actual.is_err_and(|error| self.matcher.is_match(error))
+    }
+
+    fn describe_match(&self, actual: Result<T, E>) -> Option<Description> {
+        actual
+            .err()
+            .and_then(|error| self.matcher.describe_match(error))
+            .map(|description| in_variant("Err(...)", description))
+    }
+}
+
+impl<'a, T: fmt::Debug, E, M: Matcher<&'a E>> Matcher<&'a Result<T, E>> for IsErr<M> {
+    fn matches(&self, actual: &'a Result<T, E>) -> MatchResult {
+        self.matches(actual.as_ref())
+    }
+
+    fn is_match(&self, actual: &'a Result<T, E>) -> bool {
+        self.is_match(actual.as_ref())
+    }
+
+    fn describe_match(&self, actual: &'a Result<T, E>) -> Option<Description> {
+        self.describe_match(actual.as_ref())
+    }
+}
+
+/// Matches `Ok` values whose content matches `matcher`.
+///
+/// For an `&Result<T, E>` the matcher gets an `&T`.
+pub fn ok<M>(matcher: M) -> IsOk<M> {
+    IsOk { matcher }
+}
+
+/// Matches `Err` values whose error matches `matcher`.
+///
+/// For an `&Result<T, E>` the matcher gets an `&E`.
+pub fn err<M>(matcher: M) -> IsErr<M> {
+    IsErr { matcher }
+}
+
+/// Matches any `Ok` value.
+pub fn is_ok() -> IsOk<Anything> {
+    ok(anything())
+}
+
+/// Matches any `Err` value.
+pub fn is_err() -> IsErr<Anything> {
+    err(anything())
+}
@@ -0,0 +1,134 @@
+// Copyright 2026 The hamcrest-rust Developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::fmt;
+
+use core::*;
+
+pub struct IsSome<M> {
+    matcher: M,
+}
+
+impl<M: fmt::Display> fmt::Display for IsSome<M> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Some({})", self.matcher)
+    }
+}
+
+impl<T, M: Matcher<T>> Matcher<Option<T>> for IsSome<M> {
+    fn matches(&self, actual: Option<T>) -> MatchResult {
+        match actual {
+            Some(value) => self.matcher.matches(value).map_err(in_some),
+            None => Err(Description::new().append_text("was None")),
+        }
+    }
+
+    fn is_match(&self, actual: Option<T>) -> bool {
+        actual.is_some_and(|value| self.matcher.is_match(value))
+    }
+
+    fn describe_match(&self, actual: Option<T>) -> Option<Description> {
+        actual
+            .and_then(|value| self.matcher.describe_match(value))
+            .map(in_some)
+    }
+}
+
+impl<'a, T, M: Matcher<&'a T>> Matcher<&'a Option<T>> for IsSome<M> {
+    fn matches(&self, actual: &'a Option<T>) -> MatchResult {
+        self.matches(actual.as_ref())
+    }
+
+    fn is_match(&self, actual: &'a Option<T>) -> bool {
+        self.is_match(actual.as_ref())
+    }
+
+    fn describe_match(&self, actual: &'a Option<T>) -> Option<Description> {
+        self.describe_match(actual.as_ref())
+    }
+}
+
+/// Matches `Some` values whose content matches `matcher`.
+///
+/// For an `&Option<T>` the matcher gets an `&T`.
+pub fn some<M>(matcher: M) -> IsSome<M> {
+    IsSome { matcher }
+}
+
+pub struct IsSomeWith<F> {
+    description: String,
+    predicate: F,
+}
+
+impl<F> fmt::Display for IsSomeWith<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Some({})", self.description)
+    }
+}
+
+impl<T: fmt::Debug, F: Fn(&T) -> bool> Matcher<Option<T>> for IsSomeWith<F> {
+    fn matches(&self, actual: Option<T>) -> MatchResult {
+        self.matches(&actual)
+    }
+
+    fn is_match(&self, actual: Option<T>) -> bool {
+        self.is_match(&actual)
+    }
+
+    fn describe_match(&self, actual: Option<T>) -> Option<Description> {
+        self.describe_match(&actual)
+    }
+}
+
+impl<'a, T: fmt::Debug, F: Fn(&T) -> bool> Matcher<&'a Option<T>> for IsSomeWith<F> {
+    fn matches(&self, actual: &'a Option<T>) -> MatchResult {
+        match *actual {
+            Some(ref value) if (self.predicate)(value) => success(),
+            Some(_) => Err(Description::new().append_text("was ").append_value(actual)),
+            None => Err(Description::new().append_text("was None")),
+        }
+    }
+
+    fn is_match(&self, actual: &'a Option<T>) -> bool {
+        actual.as_ref().is_some_and(|value| (self.predicate)(value))
+    }
+
+    fn describe_match(&self, actual: &'a Option<T>) -> Option<Description> {
+        if self.is_match(actual) {
+            Some(
+                Description::new()
+                    .append_text("was ")
+                    .append_value(actual)
+                    .append_text(format!(", which is Some({})", self.description)),
+            )
+        } else {
+            None
+        }
+    }
+}
+
+/// Matches `Some` values for which `predicate` returns `true`, `description`
+/// names the values it accepts.
+///
+/// ```
+/// # #[macro_use] extern crate hamcrest;
+/// # use hamcrest::prelude::*;
+/// # fn main() {
+/// assert_that!(Some(4), some_with("an even number", |n: &i32| n % 2 == 0));
+/// # }
+/// ```
+pub fn some_with<S: Into<String>, F>(description: S, predicate: F) -> IsSomeWith<F> {
+    IsSomeWith {
+        description: description.into(),
+        predicate,
+    }
+}
+
+fn in_some(description: Description) -> Description {
+    in_variant("Some(...)", description)
+}
@@ -0,0 +1,544 @@
+// Copyright 2026 The hamcrest-rust Developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Matchers for `&str` and `&String`.
+//!
+//! Indices in failure messages count characters, not bytes.
+
+use std::fmt;
+
+use core::*;
+
+/// Implements `Matcher<&str>` and `Matcher<&String>` for a type with
+/// `is_match_str(&self, &str) -> bool` and
+/// `mismatch_str(&self, &str) -> Description` methods.
+macro_rules! str_matcher {
+    ($name:ident) => {
+        impl<'a> Matcher<&'a str> for $name {
+            fn matches(&self, actual: &'a str) -> MatchResult {
+                if self.is_match_str(actual) {
+                    success()
+                } else {
+                    Err(self.mismatch_str(actual))
+                }
+            }
+
+            fn is_match(&self, actual: &'a str) -> bool {
+                self.is_match_str(actual)
+            }
+
+            fn describe_match(&self, actual: &'a str) -> Option<Description> {
+                if self.is_match_str(actual) {
+                    Some(
+                        Description::new()
+                            .append_text("was ")
+                            .append_value(actual)
+                            .append_text(format!(", which is {}", self)),
+                    )
+                } else {
+                    None
+                }
+            }
+        }
+
+        impl<'a> Matcher<&'a String> for $name {
+            fn matches(&self, actual: &'a String) -> MatchResult {
+                self.matches(&actual[..])
+            }
+
+            fn is_match(&self, actual: &'a String) -> bool {
+                self.is_match(&actual[..])
+            }
+
+            fn describe_match(&self, actual: &'a String) -> Option<Description> {
+                self.describe_match(&actual[..])
+            }
+        }
+    };
+}
+
+/// Starts the description of a mismatching string.
+fn was(actual: &str) -> Description {
+    Description::new().append_text("was ").append_value(actual)
+}
+
+/// Describes where `actual` stops agreeing with `expected`, comparing
+/// characters with `eq`.
+fn divergence<F: Fn(char, char) -> bool>(actual: &str, expected: &str, eq: F) -> String {
+    let common = actual
+        .chars()
+        .zip(expected.chars())
+        .take_while(|&(a, e)| eq(a, e))
+        .count();
+    match (actual.chars().nth(common), expected.chars().nth(common)) {
+        (Some(_), Some(_)) => format!("which differs at index {}", common),
+        (None, _) => {
+            let missing: String = expected.chars().skip(common).collect();
+            format!("which is missing {:?} at index {}", missing, common)
+        }
+        (Some(_), None) => {
+            let extra: String = actual.chars().skip(common).collect();
+            format!("which has {:?} left over at index {}", extra, common)
+        }
+    }
+}
+
+pub struct ContainsString {
+    substring: String,
+}
+
+impl fmt::Display for ContainsString {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a string containing {:?}", self.substring)
+    }
+}
+
+impl ContainsString {
+    fn is_match_str(&self, actual: &str) -> bool {
+        actual.contains(&self.substring[..])
+    }
+
+    fn mismatch_str(&self, actual: &str) -> Description {
+        // The longest start of the substring that is there, to show where
+        // the text diverges from it.
+        let found = self.substring
+            .char_indices()
+            .map(|(i, c)| &self.substring[..i + c.len_utf8()])
+            .take_while(|prefix| actual.contains(prefix))
+            .last();
+        match found {
+            Some(prefix) => {
+                let index = actual[..actual.find(prefix).unwrap()].chars().count();
+                was(actual).append_text(format!(
+                    ", which contains {:?} at index {} but not {:?}",
+                    prefix, index, self.substring
+                ))
+            }
+            None => was(actual),
+        }
+    }
+}
+
+str_matcher!(ContainsString);
+
+/// Matches strings that contain `substring`.
+pub fn contains_string<S: Into<String>>(substring: S) -> ContainsString {
+    ContainsString { substring: substring.into() }
+}
+
+pub struct StringContainsInOrder {
+    substrings: Vec<String>,
+}
+
+impl fmt::Display for StringContainsInOrder {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a string containing {:?} in order", self.substrings)
+    }
+}
+
+impl StringContainsInOrder {
+    /// Finds the substrings one after the other, returning the first one that
+    /// is missing and the byte offset its search started at.
+    fn first_missing<'b>(&'b self, actual: &str) -> Option<(&'b str, usize)> {
+        let mut offset = 0;
+        for substring in &self.substrings {
+            match actual[offset..].find(&substring[..]) {
+                Some(index) => offset += index + substring.len(),
+                None => return Some((substring, offset)),
+            }
+        }
+        None
+    }
+
+    fn is_match_str(&self, actual: &str) -> bool {
+        self.first_missing(actual).is_none()
+    }
+
+    fn mismatch_str(&self, actual: &str) -> Description {
+        let (substring, offset) = self.first_missing(actual).unwrap_or_default();
+        was(actual).append_text(format!(
+            ", which does not contain {:?} after index {}",
+            substring,
+            actual[..offset].chars().count()
+        ))
+    }
+}
+
+str_matcher!(StringContainsInOrder);
+
+/// Matches strings that contain all of `substrings`, without overlapping, in
+/// the given order.
+///
+/// ```
+/// # #[macro_use] extern crate hamcrest;
+/// # use hamcrest::prelude::*;
+/// # fn main() {
+/// let log = "connecting\nconnected\nsending 3 bytes\nclosed";
+/// assert_that!(log, string_contains_in_order(vec!["connected", "sending", "closed"]));
+/// # }
+/// ```
+pub fn string_contains_in_order<I>(substrings: I) -> StringContainsInOrder
+where
+    I: IntoIterator,
+    I::Item: Into<String>,
+{
+    StringContainsInOrder { substrings: substrings.into_iter().map(Into::into).collect() }
+}
+
+pub struct StartsWith {
+    prefix: String,
+}
+
+impl fmt::Display for StartsWith {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a string starting with {:?}", self.prefix)
+    }
+}
+
+impl StartsWith {
+    fn is_match_str(&self, actual: &str) -> bool {
+        actual.starts_with(&self.prefix[..])
+    }
+
+    fn mismatch_str(&self, actual: &str) -> Description {
+        let prefix: String = actual.chars().take(self.prefix.chars().count()).collect();
+        was(actual).append_text(format!(", {}", divergence(&prefix, &self.prefix, |a, e| a == e)))
+    }
+}
+
+str_matcher!(StartsWith);
+
+/// Matches strings that start with `prefix`.
+pub fn starts_with<S: Into<String>>(prefix: S) -> StartsWith {
+    StartsWith { prefix: prefix.into() }
+}
+
+pub struct EndsWith {
+    suffix: String,
+}
+
+impl fmt::Display for EndsWith {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a string ending with {:?}", self.suffix)
+    }
+}
+
+impl EndsWith {
+    fn is_match_str(&self, actual: &str) -> bool {
+        actual.ends_with(&self.suffix[..])
+    }
+
+    fn mismatch_str(&self, actual: &str) -> Description {
+        let len = actual.chars().count();
+        let common = actual
+            .chars()
+            .rev()
+            .zip(self.suffix.chars().rev())
+            .take_while(|&(a, e)| a == e)
+            .count();
+        let text = if common < len {
+            format!(", which differs at index {}", len - common - 1)
+        } else {
+            let missing: String = self.suffix
+                .chars()
+                .take(self.suffix.chars().count() - common)
+                .collect();
+            format!(", which is missing {:?} at the start", missing)
+        };
+        was(actual).append_text(text)
+    }
+}
+
+str_matcher!(EndsWith);
+
+/// Matches strings that end with `suffix`.
+pub fn ends_with<S: Into<String>>(suffix: S) -> EndsWith {
+    EndsWith { suffix: suffix.into() }
+}
+
+pub struct EqualToString {
+    expected: String,
+}
+
+impl fmt::Display for EqualToString {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self.expected)
+    }
+}
+
+impl EqualToString {
+    fn is_match_str(&self, actual: &str) -> bool {
+        actual == self.expected
+    }
+
+    fn mismatch_str(&self, actual: &str) -> Description {
+        was(actual).append_text(format!(", {}", divergence(actual, &self.expected, |a, e| a == e)))
+    }
+}
+
+str_matcher!(EqualToString);
+
+/// Matches strings equal to `expected`.
+///
+/// Unlike `equal_to("...")`, which only matches `&'static str`s inside
+/// matchers such as `error_message` and `panics_with`, this matches strings
+/// borrowed for any lifetime.
+///
+/// ```
+/// # #[macro_use] extern crate hamcrest;
+/// # use hamcrest::prelude::*;
+/// # fn main() {
+/// let error = "x".parse::<i32>().unwrap_err();
+/// assert_that!(error, error_message(equal_to_string("invalid digit found in string")));
+/// # }
+/// ```
+pub fn equal_to_string<S: Into<String>>(expected: S) -> EqualToString {
+    EqualToString { expected: expected.into() }
+}
+
+pub struct EqualToIgnoringCase {
+    expected: String,
+}
+
+impl fmt::Display for EqualToIgnoringCase {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?} ignoring case", self.expected)
+    }
+}
+
+fn eq_ignoring_case(a: char, b: char) -> bool {
+    a == b || a.to_lowercase().eq(b.to_lowercase())
+}
+
+impl EqualToIgnoringCase {
+    fn is_match_str(&self, actual: &str) -> bool {
+        actual.chars().count() == self.expected.chars().count() &&
+            actual
+                .chars()
+                .zip(self.expected.chars())
+                .all(|(a, e)| eq_ignoring_case(a, e))
+    }
+
+    fn mismatch_str(&self, actual: &str) -> Description {
+        was(actual).append_text(format!(
+            ", {}",
+            divergence(actual, &self.expected, eq_ignoring_case)
+        ))
+    }
+}
+
+str_matcher!(EqualToIgnoringCase);
+
+/// Matches strings equal to `expected` when upper and lower case letters are
+/// considered the same.
+pub fn equal_to_ignoring_case<S: Into<String>>(expected: S) -> EqualToIgnoringCase {
+    EqualToIgnoringCase { expected: expected.into() }
+}
+
+pub struct EqualToIgnoringWhitespace {
+    expected: String,
+}
+
+impl fmt::Display for EqualToIgnoringWhitespace {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?} ignoring whitespace", self.expected)
+    }
+}
+
+/// Drops leading and trailing whitespace and turns every other run of
+/// whitespace into a single space.
+fn collapse_whitespace(s: &str) -> String {
+    s.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+impl EqualToIgnoringWhitespace {
+    fn is_match_str(&self, actual: &str) -> bool {
+        collapse_whitespace(actual) == collapse_whitespace(&self.expected)
+    }
+
+    fn mismatch_str(&self, actual: &str) -> Description {
+        let collapsed = collapse_whitespace(actual);
+        let expected = collapse_whitespace(&self.expected);
+        was(actual).append_text(format!(
+            ", or {:?} ignoring whitespace, {}",
+            collapsed,
+            divergence(&collapsed, &expected, |a, e| a == e)
+        ))
+    }
+}
+
+str_matcher!(EqualToIgnoringWhitespace);
+
+/// Matches strings equal to `expected` when leading and trailing whitespace
+/// is ignored and other runs of whitespace are treated as a single space.
+pub fn equal_to_ignoring_whitespace<S: Into<String>>(expected: S) -> EqualToIgnoringWhitespace {
+    EqualToIgnoringWhitespace { expected: expected.into() }
+}
+
+pub struct EmptyString;
+
+impl fmt::Display for EmptyString {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "an empty string")
+    }
+}
+
+impl EmptyString {
+    fn is_match_str(&self, actual: &str) -> bool {
+        actual.is_empty()
+    }
+
+    fn mismatch_str(&self, actual: &str) -> Description {
+        was(actual)
+    }
+}
+
+str_matcher!(EmptyString);
+
+/// Matches the empty string.
+pub fn empty_string() -> EmptyString {
+    EmptyString
+}
+
+pub struct BlankString;
+
+impl fmt::Display for BlankString {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a blank string")
+    }
+}
+
+impl BlankString {
+    fn is_match_str(&self, actual: &str) -> bool {
+        actual.trim().is_empty()
+    }
+
+    fn mismatch_str(&self, actual: &str) -> Description {
+        let index = actual.chars().take_while(|c| c.is_whitespace()).count();
+        was(actual).append_text(format!(
+            ", which has a non-whitespace character at index {}",
+            index
+        ))
+    }
+}
+
+str_matcher!(BlankString);
+
+/// Matches strings that are empty or contain only whitespace.
+pub fn blank_string() -> BlankString {
+    BlankString
+}
+
+pub struct EmptyOrNoneString;
+
+impl fmt::Display for EmptyOrNoneString {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "None or an empty string")
+    }
+}
+
+impl<'a> Matcher<Option<&'a str>> for EmptyOrNoneString {
+    fn matches(&self, actual: Option<&'a str>) -> MatchResult {
+        match actual {
+            Some(s) if !s.is_empty() => {
+                Err(Description::new().append_text("was ").append_value(&actual))
+            }
+            _ => success(),
+        }
+    }
+
+    fn is_match(&self, actual: Option<&'a str>) -> bool {
+        match actual {
+            Some(actual) => actual.is_empty(),
+            None => true,
+        }
+    }
+
+    fn describe_match(&self, actual: Option<&'a str>) -> Option<Description> {
+        if self.is_match(actual) {
+            Some(Description::new().append_text("was ").append_value(&actual))
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a> Matcher<&'a Option<String>> for EmptyOrNoneString {
+    fn matches(&self, actual: &'a Option<String>) -> MatchResult {
+        self.matches(actual.as_ref().map(|s| &s[..]))
+    }
+
+    fn is_match(&self, actual: &'a Option<String>) -> bool {
+        self.is_match(actual.as_ref().map(|s| &s[..]))
+    }
+
+    fn describe_match(&self, actual: &'a Option<String>) -> Option<Description> {
+        self.describe_match(actual.as_ref().map(|s| &s[..]))
+    }
+}
+
+/// Matches `None` and `Some("")`.
+pub fn empty_or_none_string() -> EmptyOrNoneString {
+    EmptyOrNoneString
+}
+
+pub struct Trimmed<M> {
+    matcher: M,
+}
+
+impl<M: fmt::Display> fmt::Display for Trimmed<M> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} when trimmed", self.matcher)
+    }
+}
+
+impl<'a, M: Matcher<&'a str>> Matcher<&'a str> for Trimmed<M> {
+    fn matches(&self, actual: &'a str) -> MatchResult {
+        self.matcher
+            .matches(actual.trim())
+            .map_err(|mismatch| mismatch.with_label("trimmed"))
+    }
+
+    fn is_match(&self, actual: &'a str) -> bool {
+        self.matcher.is_match(actual.trim())
+    }
+
+    fn describe_match(&self, actual: &'a str) -> Option<Description> {
+        self.matcher
+            .describe_match(actual.trim())
+            .map(|description| description.with_label("trimmed"))
+    }
+}
+
+impl<'a, M: Matcher<&'a str>> Matcher<&'a String> for Trimmed<M> {
+    fn matches(&self, actual: &'a String) -> MatchResult {
+        self.matches(&actual[..])
+    }
+
+    fn is_match(&self, actual: &'a String) -> bool {
+        self.is_match(&actual[..])
+    }
+
+    fn describe_match(&self, actual: &'a String) -> Option<Description> {
+        self.describe_match(&actual[..])
+    }
+}
+
+/// Applies `matcher` to strings with their leading and trailing whitespace
+/// removed.
+///
+/// ```
+/// # #[macro_use] extern crate hamcrest;
+/// # use hamcrest::prelude::*;
+/// # fn main() {
+/// assert_that!("  42\n", trimmed(equal_to("42")));
+/// # }
+/// ```
+pub fn trimmed<M>(matcher: M) -> Trimmed<M> {
+    Trimmed { matcher }
+}
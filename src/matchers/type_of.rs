@@ -5,12 +5,12 @@
 // <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
-use std::any::TypeId;
+use std::any::{type_name, TypeId};
 use std::fmt::{self, Display, Formatter};
 
 use core::*;
 
-pub struct TypeOf(TypeId);
+pub struct TypeOf(TypeId, &'static str);
 
 impl Display for TypeOf {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
@@ -25,11 +25,26 @@ impl<T: 'static> Matcher<T> for TypeOf {
         if self.0 == type_id {
             success()
         } else {
-            Err(format!("type of {:?}", type_id))
+            Err(Description::new()
+                .append_text("was of a different type")
+                .with_expected(self.1)
+                .with_actual(type_name::<T>()))
+        }
+    }
+
+    fn is_match(&self, _: T) -> bool {
+        self.0 == TypeId::of::<T>()
+    }
+
+    fn describe_match(&self, _: T) -> Option<Description> {
+        if self.0 == TypeId::of::<T>() {
+            Some(Description::new().append_text(format!("was of type {}", self.1)))
+        } else {
+            None
         }
     }
 }
 
 pub fn type_of<T: 'static>() -> TypeOf {
-    TypeOf(TypeId::of::<T>())
+    TypeOf(TypeId::of::<T>(), type_name::<T>())
 }
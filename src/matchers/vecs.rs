@@ -10,6 +10,12 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+//! Matchers for collections.
+//!
+//! They work on references to any collection that can be iterated by
+//! reference, such as `Vec`, slices, arrays, `VecDeque`, `LinkedList`,
+//! `HashSet` and `BTreeSet`.
+
 use std::fmt;
 use std::vec::Vec;
 
@@ -26,18 +32,41 @@ impl fmt::Display for OfLen {
     }
 }
 
-impl<'a, T> Matcher<&'a Vec<T>> for OfLen {
-    fn matches(&self, actual: &Vec<T>) -> MatchResult {
-        if self.len == actual.len() {
+impl<'a, C: ?Sized> Matcher<&'a C> for OfLen
+where
+    &'a C: IntoIterator,
+{
+    fn matches(&self, actual: &'a C) -> MatchResult {
+        let len = actual.into_iter().count();
+        if self.len == len {
             success()
         } else {
-            Err(format!("was len {}", actual.len()))
+            Err(Description::new()
+                .append_text("was len ")
+                .append_value(&len))
+        }
+    }
+
+    fn is_match(&self, actual: &'a C) -> bool {
+        self.len == actual.into_iter().count()
+    }
+
+    fn describe_match(&self, actual: &'a C) -> Option<Description> {
+        let len = actual.into_iter().count();
+        if self.len == len {
+            Some(
+                Description::new()
+                    .append_text("was len ")
+                    .append_value(&len),
+            )
+        } else {
+            None
         }
     }
 }
 
 pub fn of_len(len: usize) -> OfLen {
-    OfLen { len: len }
+    OfLen { len }
 }
 
 #[derive(Clone)]
@@ -69,42 +98,79 @@ impl<T: fmt::Debug> fmt::Display for Contains<T> {
     }
 }
 
-impl<'a, T: fmt::Debug + PartialEq + Clone> Matcher<&'a Vec<T>> for Contains<T> {
-    fn matches(&self, actual: &Vec<T>) -> MatchResult {
-        let mut rem = actual.clone();
-
-        for item in self.items.iter() {
-            match rem.iter().position(|a| *item == *a) {
-                Some(idx) => {
-                    rem.remove(idx);
-                }
-                None => return Err(format!("was {}", Pretty(&actual))),
-            }
-        }
+impl<'a, T, C> Matcher<&'a C> for Contains<T>
+where
+    T: fmt::Debug + PartialEq + 'a,
+    C: fmt::Debug + ?Sized,
+    &'a C: IntoIterator<Item = &'a T>,
+{
+    fn matches(&self, actual: &'a C) -> MatchResult {
+        let elements: Vec<&T> = actual.into_iter().collect();
+        let rem = match remaining(&elements, &self.items) {
+            Some(rem) => rem,
+            None => return Err(Description::new().append_text("was ").append_value(actual)),
+        };
 
         if self.exactly && !rem.is_empty() {
-            return Err(format!("also had {}", Pretty(&rem)));
+            return Err(Description::new()
+                .append_text("also had ")
+                .append_value(&rem));
         }
 
-        if self.in_order && !contains_in_order(actual, &self.items) {
-            return Err(format!(
-                "{} does not contain {} in order",
-                Pretty(&actual),
-                Pretty(&self.items)
-            ));
+        if self.in_order && !contains_in_order(&elements, &self.items) {
+            return Err(Description::new()
+                .append_value(actual)
+                .append_text(" does not contain ")
+                .append_value(&self.items)
+                .append_text(" in order"));
         }
 
         success()
     }
+
+    fn is_match(&self, actual: &'a C) -> bool {
+        let elements: Vec<&T> = actual.into_iter().collect();
+        match remaining(&elements, &self.items) {
+            Some(rem) => {
+                (!self.exactly || rem.is_empty()) &&
+                    (!self.in_order || contains_in_order(&elements, &self.items))
+            }
+            None => false,
+        }
+    }
+
+    fn describe_match(&self, actual: &'a C) -> Option<Description> {
+        if self.is_match(actual) {
+            Some(
+                Description::new()
+                    .append_text("was ")
+                    .append_value(actual)
+                    .append_text(format!(", {}", self)),
+            )
+        } else {
+            None
+        }
+    }
+}
+
+/// Removes one element equal to each of `items` from `elements`, returns
+/// the elements left over or `None` if an item is missing.
+fn remaining<'a, T: PartialEq>(elements: &[&'a T], items: &[T]) -> Option<Vec<&'a T>> {
+    let mut rem = elements.to_vec();
+    for item in items.iter() {
+        let idx = rem.iter().position(|a| *item == **a)?;
+        rem.remove(idx);
+    }
+    Some(rem)
 }
 
-fn contains_in_order<T: fmt::Debug + PartialEq>(actual: &Vec<T>, items: &Vec<T>) -> bool {
+fn contains_in_order<T: PartialEq>(actual: &[&T], items: &[T]) -> bool {
     let mut previous = None;
 
     for item in items.iter() {
-        match actual.iter().position(|a| *item == *a) {
+        match actual.iter().position(|a| *item == **a) {
             Some(current) => {
-                if !is_next_index(&current, &previous) {
+                if !is_next_index(current, previous) {
                     return false;
                 }
                 previous = Some(current);
@@ -113,19 +179,19 @@ fn contains_in_order<T: fmt::Debug + PartialEq>(actual: &Vec<T>, items: &Vec<T>)
         }
     }
 
-    return true;
+    true
 }
 
-fn is_next_index(current_index: &usize, previous_index: &Option<usize>) -> bool {
-    if let Some(index) = *previous_index {
-        return *current_index == index + 1;
+fn is_next_index(current_index: usize, previous_index: Option<usize>) -> bool {
+    if let Some(index) = previous_index {
+        return current_index == index + 1;
     }
-    return true;
+    true
 }
 
 pub fn contains<T>(items: Vec<T>) -> Contains<T> {
     Contains {
-        items: items,
+        items,
         exactly: false,
         in_order: false,
     }
@@ -135,12 +201,12 @@ struct Pretty<'a, T: 'a>(&'a [T]);
 
 impl<'a, T: fmt::Debug> fmt::Display for Pretty<'a, T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        try!(write!(f, "["));
+        write!(f, "[")?;
         for (i, t) in self.0.iter().enumerate() {
             if i != 0 {
-                try!(write!(f, ", "));
+                write!(f, ", ")?;
             }
-            try!(write!(f, "{:?}", t));
+            write!(f, "{:?}", t)?;
         }
         write!(f, "]")
     }
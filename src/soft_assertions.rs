@@ -0,0 +1,92 @@
+// Copyright 2026 The hamcrest-rust Developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::mem;
+use std::thread;
+
+use core::{check_that, AssertionError, Matcher};
+
+/// Collects the failures of several checks and reports them all at once.
+///
+/// ```
+/// # #[macro_use] extern crate hamcrest;
+/// # use hamcrest::prelude::*;
+/// # fn main() {
+/// let mut soft = SoftAssertions::new();
+/// soft.check_that(1, less_than(2));
+/// soft.check_that("abc", matches_regex(r"^a"));
+/// soft.assert_all();
+/// # }
+/// ```
+///
+/// Dropping a `SoftAssertions` that still holds failures panics as well, so
+/// forgetting to call `assert_all` cannot hide them.
+#[derive(Default)]
+pub struct SoftAssertions {
+    failures: Vec<AssertionError>,
+}
+
+impl SoftAssertions {
+    pub fn new() -> SoftAssertions {
+        SoftAssertions::default()
+    }
+
+    /// Checks `actual` against `matcher` and records a failure, with the
+    /// file and line of the caller, if it does not match.
+    #[track_caller]
+    pub fn check_that<T, M: Matcher<T>>(&mut self, actual: T, matcher: M) -> bool {
+        match check_that(actual, matcher) {
+            Ok(_) => true,
+            Err(error) => {
+                self.failures.push(error);
+                false
+            }
+        }
+    }
+
+    pub fn has_failures(&self) -> bool {
+        !self.failures.is_empty()
+    }
+
+    pub fn failures(&self) -> &[AssertionError] {
+        &self.failures
+    }
+
+    /// Panics with every recorded failure, if there are any.
+    pub fn assert_all(mut self) {
+        let failures = mem::take(&mut self.failures);
+        if !failures.is_empty() {
+            panic!("{}", report(&failures));
+        }
+    }
+}
+
+impl Drop for SoftAssertions {
+    fn drop(&mut self) {
+        if !self.failures.is_empty() && !thread::panicking() {
+            let failures = mem::take(&mut self.failures);
+            panic!("{}", report(&failures));
+        }
+    }
+}
+
+fn report(failures: &[AssertionError]) -> String {
+    let mut report = match failures.len() {
+        1 => "1 soft assertion failed:".to_string(),
+        n => format!("{} soft assertions failed:", n),
+    };
+    for failure in failures {
+        report.push_str(&format!(
+            "\n\n{}:{}:\n{}",
+            failure.file(),
+            failure.line(),
+            failure.render_for_terminal()
+        ));
+    }
+    report
+}
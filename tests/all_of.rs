@@ -12,6 +12,32 @@ extern crate hamcrest;
 mod all_of {
 
     use hamcrest::prelude::*;
+    use std::fmt;
+
+    /// Stands in for values that cannot be cloned, like files or guards.
+    struct Handle(u32);
+
+    struct HasId(u32);
+
+    impl fmt::Display for HasId {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "id {}", self.0)
+        }
+    }
+
+    impl<'a> HamcrestMatcher<&'a Handle> for HasId {
+        fn matches(&self, actual: &'a Handle) -> Result<(), Description> {
+            if actual.0 == self.0 {
+                Ok(())
+            } else {
+                Err(Description::new().append_text(format!("was {}", actual.0)))
+            }
+        }
+    }
+
+    fn has_id(id: u32) -> HasId {
+        HasId(id)
+    }
 
     #[test]
     fn ints_less_than_and_greater_than() {
@@ -25,4 +51,86 @@ mod all_of {
             all_of!(contains(vec![1, 2]), not(contains(vec![4])))
         );
     }
+
+    #[test]
+    fn vec_of_matchers() {
+        let matchers: Vec<_> = (1..5).map(greater_than).collect();
+
+        assert_that!(5, all_of(matchers));
+    }
+
+    #[test]
+    fn vec_of_boxed_matchers() {
+        assert_that!(
+            4,
+            all_of(vec![less_than(5).boxed(), not(equal_to(3)).boxed()])
+        );
+        assert_that!(
+            3,
+            not(all_of(vec![less_than(5).boxed(), not(equal_to(3)).boxed()]))
+        );
+    }
+
+    #[test]
+    fn array_of_matchers() {
+        assert_that!(4, all_of([less_than(5), greater_than(3), greater_than(0)]));
+    }
+
+    #[test]
+    fn display() {
+        let matcher = all_of(vec![less_than(5).boxed(), equal_to(3).boxed()]);
+
+        assert_that!(matcher.to_string(), equal_to("all of (< 5, 3)".to_string()));
+    }
+
+    #[test]
+    fn report_all_matches_like_all_of() {
+        assert_that!(4, all_of!(less_than(5), greater_than(3)).report_all());
+        assert_that!(6, not(all_of!(less_than(5), greater_than(3)).report_all()));
+    }
+
+    #[test]
+    fn stops_at_the_first_mismatch() {
+        let mismatch = all_of!(less_than(1), less_than(2), less_than(3))
+            .matches(5)
+            .unwrap_err();
+
+        assert_that!(
+            mismatch.to_string(),
+            equal_to("did not match:\n  < 1: was 5".to_string())
+        );
+    }
+
+    #[test]
+    fn report_all_lists_every_mismatch() {
+        let mismatch = all_of!(less_than(1), greater_than(2), less_than(3))
+            .report_all()
+            .matches(5)
+            .unwrap_err();
+
+        assert_that!(
+            mismatch.to_string(),
+            equal_to("did not match 2 of 3:\n  [0] < 1: was 5\n  [2] < 3: was 5".to_string())
+        );
+    }
+
+    #[test]
+    fn all_of_ref_does_not_clone() {
+        assert_that!(Handle(1), all_of_ref!(has_id(1), anything()));
+        assert_that!(Handle(1), not(all_of_ref!(has_id(1), has_id(2))));
+        assert_that!(vec![Handle(1)], all_of_ref!(of_len(1), anything()));
+    }
+
+    #[test]
+    fn all_of_ref_report_all() {
+        let mismatch = all_of_ref!(has_id(2), has_id(1), has_id(3))
+            .report_all()
+            .matches(Handle(1))
+            .unwrap_err();
+
+        assert_that!(
+            mismatch.to_string(),
+            equal_to("did not match 2 of 3:\n  [0] id 2: was 1\n  [2] id 3: was 1".to_string())
+        );
+    }
 }
@@ -12,6 +12,8 @@ extern crate hamcrest;
 mod any_of {
 
     use hamcrest::prelude::*;
+    use std::cell::Cell;
+    use std::sync::Mutex;
 
     #[test]
     fn ints_less_than_and_greater_than() {
@@ -25,4 +27,74 @@ mod any_of {
             any_of!(contains(vec![1, 2, 5]), not(contains(vec![4])))
         );
     }
+
+    #[test]
+    fn vec_of_matchers() {
+        let matchers: Vec<_> = vec![1, 3, 5].into_iter().map(equal_to).collect();
+
+        assert_that!(3, any_of(matchers));
+    }
+
+    #[test]
+    fn vec_of_boxed_matchers() {
+        assert_that!(4, any_of(vec![less_than(2).boxed(), equal_to(4).boxed()]));
+        assert_that!(
+            3,
+            not(any_of(vec![less_than(2).boxed(), equal_to(4).boxed()]))
+        );
+    }
+
+    #[test]
+    fn array_of_matchers() {
+        assert_that!(4, any_of([less_than(2), greater_than(3)]));
+    }
+
+    #[test]
+    fn empty_vec_never_matches() {
+        let matchers: Vec<Box<dyn HamcrestMatcher<i32>>> = vec![];
+
+        assert_that!(4, not(any_of(matchers)));
+    }
+
+    #[test]
+    fn lists_why_each_alternative_failed() {
+        let mismatch = any_of!(less_than(2), greater_than(3))
+            .matches(3)
+            .unwrap_err();
+
+        assert_that!(
+            mismatch.to_string(),
+            equal_to("did not match any:\n  [0] < 2: was 3\n  [1] > 3: was 3".to_string())
+        );
+    }
+
+    #[test]
+    fn describes_the_alternatives_only_on_failure() {
+        let calls = Cell::new(0);
+        let counted = |n: &i32| {
+            calls.set(calls.get() + 1);
+            *n > 3
+        };
+        let matcher = any_of!(some_with("> 3", counted), some_with("> 3", counted));
+
+        assert_that!(matcher.matches(Some(4)), is(ok(anything())));
+        assert_that!(calls.get(), equal_to(1));
+
+        // Every alternative is probed, then described once all of them failed.
+        calls.set(0);
+        assert_that!(matcher.matches(Some(3)), is(err(anything())));
+        assert_that!(calls.get(), equal_to(4));
+    }
+
+    #[test]
+    #[should_panic(expected = "[0] < 2: was 3")]
+    fn unsuccessful_match() {
+        assert_that!(3, any_of!(less_than(2), greater_than(3)));
+    }
+
+    #[test]
+    fn any_of_ref_does_not_clone() {
+        assert_that!(vec![Mutex::new(1)], any_of_ref!(of_len(0), of_len(1)));
+        assert_that!(vec![Mutex::new(1)], not(any_of_ref!(of_len(0), of_len(2))));
+    }
 }
@@ -0,0 +1,60 @@
+// Copyright 2026 The hamcrest-rust Developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#[macro_use]
+extern crate hamcrest;
+
+mod assert_that {
+
+    use hamcrest::prelude::*;
+    use std::panic;
+
+    fn panic_message<F: FnOnce() + panic::UnwindSafe>(f: F) -> String {
+        *panic::catch_unwind(f)
+            .unwrap_err()
+            .downcast::<String>()
+            .unwrap()
+    }
+
+    #[test]
+    fn successful_match_with_reason() {
+        assert_that!(1, equal_to(1), "reason");
+        assert_that!(1, equal_to(1), "request {} failed", 7);
+        assert_that!(1, equal_to(1),);
+    }
+
+    #[test]
+    fn prints_the_actual_expression() {
+        let code = 404;
+        let message = panic_message(|| assert_that!(code, equal_to(200)));
+
+        assert_that!(
+            message,
+            equal_to("\nValue of: code\nExpected: 200\n    but: was 404".to_string())
+        );
+    }
+
+    #[test]
+    fn prints_the_reason() {
+        let (id, code) = (7, 404);
+        let message = panic_message(|| assert_that!(code, equal_to(200), "request {} failed", id));
+
+        assert_that!(
+            message,
+            equal_to(
+                "\nrequest 7 failed\nValue of: code\nExpected: 200\n    but: was 404".to_string(),
+            )
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "the answer")]
+    fn unsuccessful_match_with_reason() {
+        assert_that!(41, equal_to(42), "the answer");
+    }
+}
@@ -0,0 +1,74 @@
+// Copyright 2026 The hamcrest-rust Developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#[macro_use]
+extern crate hamcrest;
+
+mod check_that {
+
+    use hamcrest::core::AssertionError;
+    use hamcrest::prelude::*;
+    use std::error::Error;
+
+    fn validate(port: u16) -> Result<u16, AssertionError> {
+        check_that!(port, greater_than(1023), "port {} is reserved", port)?;
+        Ok(port)
+    }
+
+    #[test]
+    fn successful_check() {
+        assert_that!(check_that!(1, equal_to(1)).is_ok(), is(equal_to(true)));
+        assert_that!(check_that(1, equal_to(1)).is_ok(), is(equal_to(true)));
+        assert_that!(validate(8000).ok(), equal_to(Some(8000)));
+        assert_that!(validate(80).is_err(), is(equal_to(true)));
+    }
+
+    #[test]
+    fn unsuccessful_check() {
+        let port = 80;
+        let (result, line) =
+            (check_that!(port, greater_than(1023), "port {} is reserved", port), line!());
+        let error = result.unwrap_err();
+
+        assert_that!(error.reason(), equal_to(Some("port 80 is reserved")));
+        assert_that!(error.actual(), equal_to(Some("port")));
+        assert_that!(error.expected(), equal_to("> 1023"));
+        assert_that!(error.mismatch().to_string(), equal_to("was 80".to_string()));
+        assert_that!(error.file(), matches_regex(r"check_that\.rs$"));
+        assert_that!(error.line(), equal_to(line));
+        assert_that!(
+            error.to_string(),
+            equal_to(
+                "port 80 is reserved\nValue of: port\nExpected: > 1023\n    but: was 80"
+                    .to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn check_that_function() {
+        let (error, line) = (check_that(2, less_than(1)).unwrap_err(), line!());
+
+        assert_that!(error.actual(), equal_to(None));
+        assert_that!(error.line(), equal_to(line));
+        assert_that!(
+            error.to_string(),
+            equal_to("Expected: < 1\n    but: was 2".to_string())
+        );
+    }
+
+    #[test]
+    fn is_an_error() {
+        let error: Box<dyn Error> = Box::new(check_that(2, less_than(1)).unwrap_err());
+
+        assert_that!(
+            error.to_string(),
+            equal_to("Expected: < 1\n    but: was 2".to_string())
+        );
+    }
+}
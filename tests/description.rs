@@ -0,0 +1,53 @@
+// Copyright 2026 The hamcrest-rust Developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#[macro_use]
+extern crate hamcrest;
+
+mod description {
+
+    use hamcrest::prelude::*;
+
+    #[test]
+    fn renders_text_and_values() {
+        let description = Description::new().append_text("was ").append_value("abc");
+
+        assert_that!(description.to_string(), equal_to("was \"abc\"".to_string()));
+    }
+
+    #[test]
+    fn renders_nested_descriptions_indented() {
+        let description = Description::new()
+            .append_text("did not match:")
+            .append_child(
+                Description::new()
+                    .append_text("was 9")
+                    .with_label("< 5")
+                    .with_expected("5")
+                    .with_actual("9"),
+            );
+
+        assert_that!(
+            description.render(4),
+            equal_to(
+                "did not match:\n      < 5: was 9\n        expected: 5\n          actual: 9".to_string(),
+            )
+        );
+    }
+
+    #[test]
+    fn all_of_nests_the_failing_matcher() {
+        let mismatch = all_of!(less_than(5), greater_than(3)).matches(9).unwrap_err();
+
+        assert_that!(
+            mismatch.to_string(),
+            equal_to("did not match:\n  < 5: was 9".to_string())
+        );
+    }
+
+}
@@ -12,16 +12,164 @@ extern crate hamcrest;
 mod equal_to {
 
     use hamcrest::prelude::*;
+    use hamcrest::core::Fragment;
+
+    #[derive(Debug, PartialEq)]
+    struct Config {
+        name: &'static str,
+        port: u16,
+        hosts: Vec<&'static str>,
+    }
+
+    fn mismatch<T: PartialEq + ::std::fmt::Debug>(actual: T, expected: T) -> String {
+        equal_to(expected).matches(actual).unwrap_err().to_string()
+    }
 
     #[test]
     fn equality_of_ints() {
         assert_that!(1, is(equal_to(1)));
     }
 
+    #[test]
+    fn expected_type_is_inferred_from_the_actual_value() {
+        let bytes: Vec<u8> = vec![];
+        let missing: Option<u32> = None;
+
+        assert_that!(bytes, equal_to(vec![]));
+        assert_that!(missing, equal_to(None));
+    }
+
     #[test]
     #[should_panic]
     fn unsuccessful_match() {
         assert_that!(2, is(equal_to(1)));
     }
 
+    #[test]
+    fn short_values_are_printed_inline() {
+        assert_that!(mismatch(2, 1), equal_to("was 2".to_string()));
+        assert_that!(mismatch(vec![1, 2], vec![1, 3]), equal_to("was [1, 2]".to_string()));
+    }
+
+    #[test]
+    fn multi_line_values_are_diffed() {
+        let expected = Config {
+            name: "server",
+            port: 8080,
+            hosts: vec!["a", "b"],
+        };
+        let actual = Config {
+            name: "server",
+            port: 8081,
+            hosts: vec!["a", "b", "c"],
+        };
+
+        assert_that!(
+            mismatch(actual, expected),
+            equal_to(concat!(
+                "was different (-expected +actual):\n",
+                "    Config {\n",
+                "        name: \"server\",\n",
+                "  -     port: 8080,\n",
+                "  +     port: 8081,\n",
+                "        hosts: [\n",
+                "            \"a\",\n",
+                "            \"b\",\n",
+                "  +         \"c\",\n",
+                "        ],\n",
+                "    }"
+            ).to_string())
+        );
+    }
+
+    #[test]
+    fn unchanged_lines_far_from_changes_are_skipped() {
+        let expected: Vec<u32> = (0..20).collect();
+        let mut actual = expected.clone();
+        actual[10] = 100;
+
+        let mismatch = mismatch(actual, expected);
+
+        assert_that!(&mismatch, contains_string("  ...\n"));
+        assert_that!(&mismatch, contains_string("  -     10,\n  +     100,\n"));
+        assert_that!(&mismatch, not(contains_string("  2,")));
+    }
+
+    #[test]
+    fn large_values_with_a_small_change_are_diffed() {
+        let expected: Vec<u32> = (0..20_000).collect();
+        let mut actual = expected.clone();
+        actual[10_000] = 0;
+
+        let mismatch = mismatch(actual, expected);
+
+        assert_that!(&mismatch, starts_with("was different (-expected +actual):\n    ...\n"));
+        assert_that!(&mismatch, contains_string("  -     10000,\n  +     0,\n"));
+    }
+
+    #[test]
+    fn large_values_that_differ_everywhere_are_printed_inline() {
+        let expected: Vec<u32> = (0..2_000).collect();
+        let actual: Vec<u32> = (0..2_000).rev().collect();
+
+        let mismatch = mismatch(actual, expected);
+
+        assert_that!(&mismatch, starts_with("was [1999, 1998, "));
+    }
+
+    #[test]
+    fn multi_line_strings_are_diffed_by_line() {
+        let expected = "[server]\nname = \"web\"\nport = 8080\n".to_string();
+        let actual = "[server]\nname = \"web\"\nport = 8081\n".to_string();
+
+        assert_that!(
+            mismatch(actual, expected),
+            equal_to(
+                concat!(
+                    "was different (-expected +actual):\n",
+                    "    [server]\n",
+                    "    name = \"web\"\n",
+                    "  - port = 8080\n",
+                    "  + port = 8081"
+                ).to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn a_missing_final_newline_is_noted() {
+        let expected = "[server]\nname = \"web\"\nport = 8080\n".to_string();
+        let actual = "[server]\nname = \"web\"\nport = 8081".to_string();
+
+        assert_that!(
+            mismatch(actual, expected),
+            equal_to(
+                concat!(
+                    "was different (-expected +actual):\n",
+                    "    [server]\n",
+                    "    name = \"web\"\n",
+                    "  - port = 8080\n",
+                    "  + port = 8081\n",
+                    "    (only the expected value ends with a newline)"
+                ).to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn changed_characters_are_marked() {
+        let description = equal_to("a long line of text that does not fit on one line, changed here")
+            .matches("a long line of text that does not fit on one line, altered here")
+            .unwrap_err();
+
+        let line = &description.children()[0];
+        assert_that!(
+            line.fragments(),
+            equal_to(&[
+                Fragment::Removed("- \"a long line of text that does not fit on one line, ".to_string()),
+                Fragment::RemovedChars("chang".to_string()),
+                Fragment::Removed("ed here\"".to_string()),
+            ][..])
+        );
+    }
 }
@@ -0,0 +1,172 @@
+// Copyright 2026 The hamcrest-rust Developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#[macro_use]
+extern crate hamcrest;
+
+mod error {
+
+    use std::error::Error;
+    use std::fmt;
+    use std::io;
+
+    use hamcrest::prelude::*;
+
+    #[derive(Debug)]
+    struct LoadError {
+        path: &'static str,
+        source: io::Error,
+    }
+
+    impl fmt::Display for LoadError {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "could not load {}", self.path)
+        }
+    }
+
+    impl Error for LoadError {
+        fn source(&self) -> Option<&(dyn Error + 'static)> {
+            Some(&self.source)
+        }
+    }
+
+    fn load_error(kind: io::ErrorKind) -> LoadError {
+        LoadError {
+            path: "config.toml",
+            source: io::Error::new(kind, "disk says no"),
+        }
+    }
+
+    #[test]
+    fn error_message_matches_to_string() {
+        let error = load_error(io::ErrorKind::NotFound);
+
+        assert_that!(&error, error_message(matches_regex("config\\.toml$")));
+        assert_that!(&error as &dyn Error, error_message(matches_regex("^could not")));
+    }
+
+    #[test]
+    fn error_message_equal_to_a_str() {
+        let error = "x".parse::<i32>().unwrap_err();
+
+        assert_that!(&error, error_message(equal_to_string("invalid digit found in string")));
+        assert_that!(
+            error,
+            not(error_message(equal_to_string("cannot parse integer from empty string")))
+        );
+    }
+
+    #[test]
+    fn error_message_reports_the_message() {
+        let error = check_that(
+            load_error(io::ErrorKind::NotFound),
+            error_message(matches_regex("^timed out")),
+        ).unwrap_err();
+
+        assert_that!(error.expected(), equal_to("an error with message ^timed out"));
+        assert_that!(
+            error.mismatch().to_string(),
+            equal_to("message: was \"could not load config.toml\"".to_string())
+        );
+    }
+
+    #[test]
+    fn error_kind_of_io_errors() {
+        let error = io::Error::new(io::ErrorKind::NotFound, "gone");
+
+        assert_that!(&error, error_kind(io::ErrorKind::NotFound));
+        assert_that!(&error, not(error_kind(io::ErrorKind::PermissionDenied)));
+
+        let failure = check_that(&error, error_kind(io::ErrorKind::PermissionDenied)).unwrap_err();
+        assert_that!(
+            failure.mismatch().to_string(),
+            equal_to("was an error of kind NotFound: gone".to_string())
+        );
+    }
+
+    #[test]
+    fn error_kind_inside_results() {
+        let result: Result<(), io::Error> = Err(io::ErrorKind::TimedOut.into());
+
+        assert_that!(&result, err(error_kind(io::ErrorKind::TimedOut)));
+        assert_that!(result, err(error_kind(io::ErrorKind::TimedOut)));
+    }
+
+    #[test]
+    fn caused_by_finds_a_cause() {
+        let error = load_error(io::ErrorKind::NotFound);
+
+        assert_that!(&error, caused_by::<io::Error, _>(error_kind(io::ErrorKind::NotFound)));
+        assert_that!(&error, caused_by::<io::Error, _>(anything()));
+    }
+
+    #[test]
+    fn caused_by_reports_non_matching_causes() {
+        let error = check_that(
+            load_error(io::ErrorKind::NotFound),
+            caused_by::<io::Error, _>(error_kind(io::ErrorKind::TimedOut)),
+        ).unwrap_err();
+
+        let mismatch = error.mismatch().to_string();
+        assert_that!(&mismatch, starts_with("had no matching cause of type "));
+        assert_that!(
+            &mismatch,
+            ends_with(":\n  cause [0]: was an error of kind NotFound: disk says no")
+        );
+    }
+
+    #[test]
+    fn caused_by_reports_the_chain_without_such_cause() {
+        let error = check_that(
+            load_error(io::ErrorKind::NotFound),
+            caused_by::<fmt::Error, _>(anything()),
+        ).unwrap_err();
+
+        let mismatch = error.mismatch().to_string();
+        assert_that!(&mismatch, starts_with("had no cause of type "));
+        assert_that!(&mismatch, ends_with("Error\n  cause [0]: disk says no"));
+    }
+
+    fn boxed(kind: io::ErrorKind) -> Result<(), Box<dyn Error>> {
+        Err(Box::new(load_error(kind)))
+    }
+
+    fn boxed_send_sync(message: &str) -> Result<(), Box<dyn Error + Send + Sync>> {
+        Err(message.into())
+    }
+
+    #[test]
+    fn error_message_of_boxed_errors() {
+        assert_that!(
+            boxed(io::ErrorKind::NotFound),
+            err(error_message(equal_to_string("could not load config.toml")))
+        );
+        assert_that!(&boxed(io::ErrorKind::NotFound), err(error_message(ends_with("toml"))));
+        assert_that!(boxed_send_sync("boom"), err(error_message(equal_to_string("boom"))));
+        assert_that!(&boxed_send_sync("boom"), err(not(error_message(equal_to_string("bang")))));
+    }
+
+    #[test]
+    fn caused_by_in_boxed_errors() {
+        assert_that!(
+            boxed(io::ErrorKind::NotFound),
+            err(caused_by::<io::Error, _>(error_kind(io::ErrorKind::NotFound)))
+        );
+        assert_that!(
+            &boxed(io::ErrorKind::NotFound),
+            err(not(caused_by::<io::Error, _>(error_kind(io::ErrorKind::TimedOut))))
+        );
+
+        let error: Box<dyn Error + Send + Sync> = Box::new(load_error(io::ErrorKind::TimedOut));
+        assert_that!(
+            &error,
+            caused_by::<io::Error, _>(error_message(equal_to_string("disk says no")))
+        );
+        assert_that!(error, not(caused_by::<fmt::Error, _>(anything())));
+    }
+}
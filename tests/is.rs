@@ -0,0 +1,93 @@
+// Copyright 2026 The hamcrest-rust Developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#[macro_use]
+extern crate hamcrest;
+
+mod is {
+
+    use hamcrest::prelude::*;
+    use std::fmt;
+
+    /// Only answers the boolean question, asking for a mismatch is a bug.
+    struct Even;
+
+    impl fmt::Display for Even {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "even")
+        }
+    }
+
+    impl HamcrestMatcher<u32> for Even {
+        fn matches(&self, _: u32) -> Result<(), Description> {
+            panic!("mismatch should not have been described")
+        }
+
+        fn is_match(&self, actual: u32) -> bool {
+            actual & 1 == 0
+        }
+    }
+
+    #[test]
+    fn is_not_only_asks_for_a_boolean() {
+        assert_that!(3, is_not(Even));
+    }
+
+    #[test]
+    fn any_of_only_asks_for_a_boolean() {
+        assert_that!(4, any_of!(Even, equal_to(3)));
+    }
+
+    #[test]
+    fn describe_mismatch() {
+        assert_that!(
+            equal_to(1).describe_mismatch(2).to_string(),
+            equal_to("was 2".to_string())
+        );
+        assert_that!(equal_to(1).describe_mismatch(1).is_empty(), is(equal_to(true)));
+    }
+
+    #[test]
+    fn describe_to() {
+        let description = is(less_than(5)).describe_to(Description::new().append_text("a value "));
+
+        assert_that!(description.to_string(), equal_to("a value < 5".to_string()));
+    }
+
+    #[test]
+    #[should_panic(expected = "but: was 5, which is 5")]
+    fn is_not_reports_the_actual_value() {
+        assert_that!(5, not(equal_to(5)));
+    }
+
+    #[test]
+    fn is_not_describes_why_the_inner_matcher_matched() {
+        assert_that!(
+            not(less_than(5)).matches(4).unwrap_err().to_string(),
+            equal_to("was 4, which is < 5".to_string())
+        );
+        assert_that!(
+            not(all_of!(less_than(5), greater_than(3)))
+                .matches(4)
+                .unwrap_err()
+                .to_string(),
+            equal_to(
+                "matched all of:\n  < 5: was 4, which is < 5\n  > 3: was 4, which is > 3".to_string(),
+            )
+        );
+    }
+
+    #[test]
+    fn double_negation_describes_the_inner_mismatch() {
+        assert_that!(
+            not(not(equal_to(5))).matches(4).unwrap_err().to_string(),
+            equal_to("was 4".to_string())
+        );
+    }
+
+}
@@ -0,0 +1,118 @@
+// Copyright 2026 The hamcrest-rust Developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#[macro_use]
+extern crate hamcrest;
+
+mod items {
+
+    use std::collections::BTreeSet;
+
+    use hamcrest::prelude::*;
+
+    #[test]
+    fn has_items_allows_other_elements() {
+        let numbers = vec![1, 5, 10];
+
+        assert_that!(&numbers, has_items((greater_than(&2), less_than(&3))));
+        assert_that!(&numbers, has_items!(equal_to(&10)));
+        assert_that!(&numbers, has_items([greater_than(&4), greater_than(&4)]));
+        let three_big = [greater_than(&4), greater_than(&4), greater_than(&4)];
+        assert_that!(&numbers, not(has_items(three_big)));
+    }
+
+    #[test]
+    fn overlapping_matchers_are_assigned_by_bipartite_matching() {
+        // A greedy assignment gives 2 to the first matcher and leaves nothing
+        // for the second one.
+        let numbers = [2, 1];
+
+        assert_that!(&numbers, has_items!(less_than(&3), equal_to(&2)));
+        assert_that!(&numbers, contains_in_any_order!(less_than(&3), equal_to(&2)));
+    }
+
+    #[test]
+    fn has_items_reports_unsatisfied_matchers() {
+        let error = check_that(
+            &vec![1, 5],
+            has_items!(greater_than(&4), equal_to(&5), equal_to(&7)),
+        ).unwrap_err();
+
+        assert_that!(error.expected(), equal_to("having items (> 4, 5, 7)"));
+        assert_that!(
+            error.mismatch().to_string(),
+            equal_to(
+                "was [1, 5], which did not match:\n  \
+                 [1] 5: matched only elements [1], which other matchers needed\n  \
+                 [2] 7: matched no element".to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn any_order_requires_every_element_to_be_matched() {
+        let set: BTreeSet<&str> = vec!["a", "bb", "ccc"].into_iter().collect();
+
+        assert_that!(
+            &set,
+            contains_in_any_order!(equal_to(&"ccc"), equal_to(&"a"), equal_to(&"bb"))
+        );
+        assert_that!(&set, not(contains_in_any_order!(equal_to(&"a"), equal_to(&"bb"))));
+
+        let error = check_that(&set, contains_in_any_order!(equal_to(&"a"), equal_to(&"bb")))
+            .unwrap_err();
+        assert_that!(
+            error.mismatch().to_string(),
+            equal_to(
+                "was {\"a\", \"bb\", \"ccc\"}, which did not match:\n  \
+                 element [2]: \"ccc\" was not expected".to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn exactly_matching_checks_positions() {
+        let numbers = vec![1, 2, 3];
+
+        assert_that!(
+            &numbers,
+            contains_exactly_matching!(equal_to(&1), less_than(&3), anything())
+        );
+        assert_that!(
+            &numbers,
+            not(contains_exactly_matching!(less_than(&3), equal_to(&1), anything()))
+        );
+        let positive = vec![greater_than(&0), greater_than(&0), greater_than(&0)];
+        assert_that!(&numbers[..], contains_exactly_matching(positive));
+    }
+
+    #[test]
+    fn exactly_matching_reports_each_position() {
+        let error = check_that(
+            &vec![1, 2, 3],
+            contains_exactly_matching!(equal_to(&1), equal_to(&3)),
+        ).unwrap_err();
+
+        assert_that!(error.expected(), equal_to("containing exactly (1, 3)"));
+        assert_that!(
+            error.mismatch().to_string(),
+            equal_to(
+                "was [1, 2, 3], which did not match:\n  \
+                 [1] 3: was 2\n  \
+                 element [2]: 3 was not expected".to_string()
+            )
+        );
+
+        let error = check_that(&vec![1], contains_exactly_matching!(equal_to(&1), equal_to(&2)))
+            .unwrap_err();
+        assert_that!(
+            error.mismatch().to_string(),
+            equal_to("was [1], which did not match:\n  [1] 2: was missing".to_string())
+        );
+    }
+}
@@ -0,0 +1,74 @@
+// Copyright 2026 The hamcrest-rust Developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#[macro_use]
+extern crate hamcrest;
+
+mod matcher_ext {
+
+    use hamcrest::prelude::*;
+
+    #[test]
+    fn and_matches_both() {
+        assert_that!(4, greater_than(3).and(less_than(5)));
+        assert_that!(6, not(greater_than(3).and(less_than(5))));
+    }
+
+    #[test]
+    fn or_matches_either() {
+        assert_that!(1, less_than(2).or(greater_than(3)));
+        assert_that!(&vec![1, 2, 3], contains(vec![5]).or(of_len(3)));
+    }
+
+    #[test]
+    fn not_negates() {
+        assert_that!(1, equal_to(2).not());
+        assert_that!(2, equal_to(2).not().not());
+    }
+
+    #[test]
+    fn chains_read_left_to_right() {
+        assert_that!(
+            4,
+            greater_than(3)
+                .and(less_than(5))
+                .or(equal_to(10))
+                .and(equal_to(5).not())
+        );
+    }
+
+    #[test]
+    fn described_as_replaces_the_description() {
+        let matcher = greater_than(0).and(less_than(10)).described_as("a digit");
+
+        assert_that!(matcher.to_string(), equal_to("a digit".to_string()));
+        assert_that!(5, matcher);
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected: a digit")]
+    fn unsuccessful_described_as() {
+        assert_that!(
+            10,
+            greater_than(0).and(less_than(10)).described_as("a digit")
+        );
+    }
+
+    #[test]
+    fn boxed_matchers_can_be_mixed() {
+        let matchers = vec![
+            less_than(5).boxed(),
+            equal_to(4).boxed(),
+            anything().boxed(),
+        ];
+
+        for matcher in matchers {
+            assert_that!(4, matcher);
+        }
+    }
+}
@@ -0,0 +1,36 @@
+// Copyright 2026 The hamcrest-rust Developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#[macro_use]
+extern crate hamcrest;
+
+// The panic hook is global and `panics` replaces it on first use, so this
+// test has a binary of its own.
+mod panic_hook {
+
+    use std::panic;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use hamcrest::prelude::*;
+
+    static REPORTED: AtomicUsize = AtomicUsize::new(0);
+
+    #[test]
+    fn panics_outside_the_matcher_reach_the_previous_hook() {
+        panic::set_hook(Box::new(|_| {
+            REPORTED.fetch_add(1, Ordering::SeqCst);
+        }));
+
+        assert_that!(|| panic!("inner"), panics());
+        assert_that!(REPORTED.load(Ordering::SeqCst), equal_to(0));
+
+        let result = panic::catch_unwind(|| panic!("outer"));
+        assert_that!(result.is_err(), is(equal_to(true)));
+        assert_that!(REPORTED.load(Ordering::SeqCst), equal_to(1));
+    }
+}
@@ -0,0 +1,69 @@
+// Copyright 2026 The hamcrest-rust Developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#[macro_use]
+extern crate hamcrest;
+
+mod panics {
+
+    use std::panic;
+
+    use hamcrest::prelude::*;
+
+    #[test]
+    fn closures_that_panic() {
+        assert_that!(|| panic!("boom"), panics());
+        assert_that!(|| panic!("boom {}", 1), panics());
+        assert_that!(|| (), not(panics()));
+    }
+
+    #[test]
+    fn closures_that_do_not_panic() {
+        let error = check_that(|| (), panics()).unwrap_err();
+
+        assert_that!(error.expected(), equal_to("a closure that panics"));
+        assert_that!(error.mismatch().to_string(), equal_to("did not panic".to_string()));
+    }
+
+    #[test]
+    fn static_and_formatted_messages() {
+        assert_that!(|| panic!("boom"), panics_with(matches_regex("^boom$")));
+        assert_that!(|| panic!("boom {}", 1), panics_with(matches_regex("^boom 1$")));
+    }
+
+    #[test]
+    fn message_equal_to_a_str() {
+        assert_that!(|| panic!("boom"), panics_with(equal_to_string("boom")));
+        assert_that!(|| panic!("boom {}", 1), panics_with(equal_to_string("boom 1")));
+        assert_that!(|| panic!("boom"), not(panics_with(equal_to_string("bang"))));
+    }
+
+    #[test]
+    fn mismatching_message() {
+        let error = check_that(|| panic!("boom"), panics_with(matches_regex("^bang$"))).unwrap_err();
+
+        assert_that!(error.expected(), equal_to("a closure that panics with message ^bang$"));
+        assert_that!(
+            error.mismatch().to_string(),
+            equal_to("panic message: was \"boom\"".to_string())
+        );
+    }
+
+    #[test]
+    fn payload_that_is_not_a_string() {
+        let error = check_that(
+            || panic::panic_any(42),
+            panics_with(matches_regex("42")),
+        ).unwrap_err();
+
+        assert_that!(
+            error.mismatch().to_string(),
+            equal_to("panicked with a payload that is not a string".to_string())
+        );
+    }
+}
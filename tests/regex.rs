@@ -11,7 +11,12 @@ extern crate hamcrest;
 
 mod regex {
 
+    use std::borrow::Cow;
+    use std::ffi::OsStr;
+
+    use hamcrest::matchers::regex::{Captures, MatchesPatterns, MatchesRegex, NamedCapture};
     use hamcrest::prelude::*;
+
     #[test]
     fn successful_match() {
         assert_that!("123", matches_regex(r"^\d+$"));
@@ -28,4 +33,183 @@ mod regex {
         assert_that!("abc", matches_regex(r"\d"));
     }
 
+    #[test]
+    fn full_match() {
+        assert_that!("123", fully_matches_regex(r"\d+"));
+        assert_that!("a123", not(fully_matches_regex(r"\d+")));
+        assert_that!("123a", not(fully_matches_regex(r"\d+")));
+        assert_that!("ab", fully_matches_regex("a|ab"));
+
+        assert_that!(
+            fully_matches_regex(r"\d+").to_string(),
+            equal_to(r"fully matching \d+".to_string())
+        );
+    }
+
+    #[test]
+    fn invalid_patterns_are_errors() {
+        assert_that!(MatchesRegex::new("(").is_err(), is(equal_to(true)));
+        assert_that!(MatchesRegex::full("(").is_err(), is(equal_to(true)));
+        assert_that!(Captures::new("(", [anything()]).is_err(), is(equal_to(true)));
+        assert_that!(NamedCapture::new("(", "name", anything()).is_err(), is(equal_to(true)));
+        assert_that!(MatchesRegex::new(r"\d").is_ok(), is(equal_to(true)));
+    }
+
+    #[test]
+    fn numbered_groups() {
+        assert_that!("v1.42", captures(r"(\d+)\.(\d+)", (equal_to("1"), equal_to("42"))));
+        assert_that!("v1.42", captures(r"(\d+)\.(\d+)", [fully_matches_regex(r"\d+")]));
+        assert_that!("v1.42", not(captures(r"(\d+)\.(\d+)", (equal_to("2"), anything()))));
+    }
+
+    #[test]
+    fn numbered_groups_report_every_mismatch() {
+        let error = check_that(
+            "v1.42",
+            captures(r"(\d+)\.(\d+)(-\w+)?", (equal_to("2"), equal_to("42"), anything())),
+        ).unwrap_err();
+
+        assert_that!(
+            error.expected(),
+            equal_to(r#"matching (\d+)\.(\d+)(-\w+)? with groups ("2", "42", anything)"#)
+        );
+        assert_that!(
+            error.mismatch().to_string(),
+            equal_to(
+                "was \"v1.42\", with groups that did not match:\n  \
+                 group 1: was \"1\"\n  \
+                 group 3: was not captured".to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn no_match_for_groups() {
+        let error = check_that("v1", captures(r"(\d+)\.(\d+)", [anything()])).unwrap_err();
+
+        assert_that!(error.mismatch().to_string(), equal_to("was \"v1\"".to_string()));
+    }
+
+    #[test]
+    fn named_groups() {
+        let date = r"(?P<year>\d{4})-(?P<month>\d{2})";
+
+        assert_that!("2024-05", named_capture(date, "year", equal_to("2024")));
+        assert_that!("2024-05", not(named_capture(date, "month", equal_to("06"))));
+
+        let error = check_that("2024-05", named_capture(date, "month", equal_to("06"))).unwrap_err();
+        assert_that!(
+            error.expected(),
+            equal_to(&format!("matching {} with group `month` \"06\"", date)[..])
+        );
+        assert_that!(
+            error.mismatch().to_string(),
+            equal_to(
+                "was \"2024-05\", with a group that did not match:\n  group `month`: was \"05\"".to_string()
+            )
+        );
+
+        let error = check_that("2024-05", named_capture(date, "day", anything())).unwrap_err();
+        assert_that!(
+            error.mismatch().to_string(),
+            equal_to(
+                "was \"2024-05\", with a group that did not match:\n  group `day`: was not captured".to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn group_is_match_agrees_with_matches() {
+        let numbered = captures(r"(\d+)\.(\d+)(-\w+)?", (equal_to("1"), anything(), anything()));
+        let named = named_capture(r"(?P<major>\d+)\.(?P<minor>\d+)", "minor", equal_to("42"));
+        for actual in &["v1.42", "v1.42-beta", "v2.42", "v1.41", "v1"] {
+            assert_that!(numbered.is_match(*actual), equal_to(numbered.matches(*actual).is_ok()));
+            assert_that!(named.is_match(*actual), equal_to(named.matches(*actual).is_ok()));
+        }
+    }
+
+    #[test]
+    fn owned_and_borrowed_strings() {
+        let owned = "abc123".to_string();
+
+        assert_that!(&owned, matches_regex(r"\d+"));
+        assert_that!(owned.clone(), fully_matches_regex(r"[a-z]+\d+"));
+        assert_that!(Cow::Borrowed("abc"), does_not(match_regex(r"\d")));
+        assert_that!(Cow::Owned::<str>(owned), matches_regex("^abc"));
+        assert_that!(OsStr::new("file.txt"), matches_regex(r"\.txt$"));
+
+        let error = check_that("abc".to_string(), matches_regex(r"\d")).unwrap_err();
+        assert_that!(error.mismatch().to_string(), equal_to("was \"abc\"".to_string()));
+    }
+
+    #[test]
+    fn byte_slices() {
+        let payload: &[u8] = b"\x02HELO\xff\x03";
+
+        assert_that!(payload, matches_regex(r"(?-u)\x02HELO\xff"));
+        assert_that!(payload, fully_matches_regex(r"(?-u)\x02[A-Z]+\xff\x03"));
+        assert_that!(payload, not(matches_regex("QUIT")));
+
+        let error = check_that(payload, matches_regex("QUIT")).unwrap_err();
+        assert_that!(
+            error.mismatch().to_string(),
+            equal_to(r#"was b"\x02HELO\xff\x03""#.to_string())
+        );
+    }
+
+    #[test]
+    fn all_patterns() {
+        let line = "12:00:01 ERROR connection reset";
+
+        assert_that!(line, matches_all_patterns(&[r"^\d{2}:", "ERROR", "reset$"]));
+        assert_that!(&line.to_string(), matches_all_patterns(vec!["ERROR"]));
+        assert_that!(line, not(matches_all_patterns(&["ERROR", "WARN"])));
+
+        let error = check_that(line, matches_all_patterns(&["ERROR", "WARN", "refused$"])).unwrap_err();
+        assert_that!(
+            error.expected(),
+            equal_to(r#"matching all of the patterns ["ERROR", "WARN", "refused$"]"#)
+        );
+        assert_that!(
+            error.mismatch().to_string(),
+            equal_to(
+                "was \"12:00:01 ERROR connection reset\", which matched 1 of 3 patterns:\n  \
+                 matched: ERROR\n  \
+                 did not match: WARN\n  \
+                 did not match: refused$".to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn any_pattern() {
+        assert_that!("WARN disk full", matches_any_pattern(&["ERROR", "WARN"]));
+        assert_that!("INFO started", not(matches_any_pattern(&["ERROR", "WARN"])));
+
+        let error = check_that("INFO started", matches_any_pattern(&["ERROR", "WARN"])).unwrap_err();
+        assert_that!(
+            error.mismatch().to_string(),
+            equal_to(
+                "was \"INFO started\", which matched 0 of 2 patterns:\n  \
+                 did not match: ERROR\n  \
+                 did not match: WARN".to_string()
+            )
+        );
+
+        let error = check_that("WARN", not(matches_any_pattern(&["ERROR", "WARN"]))).unwrap_err();
+        assert_that!(
+            error.mismatch().to_string(),
+            equal_to(
+                "was \"WARN\", which matched 1 of 2 patterns:\n  \
+                 did not match: ERROR\n  \
+                 matched: WARN".to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn invalid_pattern_sets_are_errors() {
+        assert_that!(MatchesPatterns::all(&["a", "("]).is_err(), is(equal_to(true)));
+        assert_that!(MatchesPatterns::any(&["a", "b"]).is_ok(), is(equal_to(true)));
+    }
 }
@@ -0,0 +1,69 @@
+// Copyright 2026 The hamcrest-rust Developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#[macro_use]
+extern crate hamcrest;
+
+mod result {
+
+    use hamcrest::prelude::*;
+
+    fn parse(s: &str) -> Result<i32, String> {
+        s.parse().map_err(|_| format!("not a number: {}", s))
+    }
+
+    #[test]
+    fn ok_with_a_matching_value() {
+        assert_that!(parse("4"), ok(equal_to(4)));
+        assert_that!(parse("4"), is_ok());
+        assert_that!(&parse("4"), ok(greater_than(&3)));
+    }
+
+    #[test]
+    fn err_with_a_matching_error() {
+        assert_that!(parse("x"), err(equal_to("not a number: x".to_string())));
+        assert_that!(parse("x"), is_err());
+        assert_that!(&parse("x"), is_err());
+    }
+
+    #[test]
+    fn ok_reports_the_error() {
+        let error = check_that(parse("x"), ok(equal_to(4))).unwrap_err();
+
+        assert_that!(error.expected(), equal_to("Ok(4)"));
+        assert_that!(
+            error.mismatch().to_string(),
+            equal_to("was Err(\"not a number: x\")".to_string())
+        );
+    }
+
+    #[test]
+    fn ok_reports_the_inner_mismatch() {
+        let error = check_that(parse("2"), ok(greater_than(3))).unwrap_err();
+
+        assert_that!(error.mismatch().to_string(), equal_to("Ok(...): was 2".to_string()));
+    }
+
+    #[test]
+    fn err_reports_the_value() {
+        let error = check_that(&parse("4"), is_err()).unwrap_err();
+
+        assert_that!(error.expected(), equal_to("Err(anything)"));
+        assert_that!(error.mismatch().to_string(), equal_to("was Ok(4)".to_string()));
+    }
+
+    #[test]
+    fn negation_describes_the_match() {
+        let error = check_that(parse("4"), not(ok(equal_to(4)))).unwrap_err();
+
+        assert_that!(
+            error.mismatch().to_string(),
+            equal_to("Ok(...): was 4, which is 4".to_string())
+        );
+    }
+}
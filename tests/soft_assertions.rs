@@ -0,0 +1,65 @@
+// Copyright 2026 The hamcrest-rust Developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#[macro_use]
+extern crate hamcrest;
+
+mod soft_assertions {
+
+    use hamcrest::prelude::*;
+    use std::panic;
+
+    #[test]
+    fn successful_checks() {
+        let mut soft = SoftAssertions::new();
+        assert_that!(soft.check_that(1, less_than(2)), is(equal_to(true)));
+        assert_that!(
+            soft.check_that("abc", matches_regex("b")),
+            is(equal_to(true))
+        );
+        assert_that!(soft.has_failures(), is(equal_to(false)));
+        soft.assert_all();
+    }
+
+    #[test]
+    #[should_panic(expected = "2 soft assertions failed")]
+    fn unsuccessful_checks() {
+        let mut soft = SoftAssertions::new();
+        soft.check_that(3, less_than(2));
+        soft.check_that(1, less_than(2));
+        soft.check_that(4, equal_to(5));
+        soft.assert_all();
+    }
+
+    #[test]
+    fn reports_every_failure_with_its_location() {
+        let result = panic::catch_unwind(|| {
+            let mut soft = SoftAssertions::new();
+            soft.check_that(3, less_than(2));
+            soft.check_that(4, equal_to(5));
+            soft.assert_all();
+        });
+        let message = *result.unwrap_err().downcast::<String>().unwrap();
+
+        assert_that!(
+            &*message,
+            matches_regex(r"soft_assertions\.rs:\d+:\nExpected: < 2\n    but: was 3")
+        );
+        assert_that!(
+            &*message,
+            matches_regex(r"soft_assertions\.rs:\d+:\nExpected: 5\n    but: was 4")
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "1 soft assertion failed")]
+    fn dropping_with_failures_panics() {
+        let mut soft = SoftAssertions::new();
+        soft.check_that(3, less_than(2));
+    }
+}
@@ -0,0 +1,68 @@
+// Copyright 2026 The hamcrest-rust Developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#[macro_use]
+extern crate hamcrest;
+
+mod some {
+
+    use hamcrest::prelude::*;
+
+    #[test]
+    fn some_with_a_matching_value() {
+        assert_that!(Some(4), some(equal_to(4)));
+        assert_that!(Some(4), some(greater_than(3)));
+    }
+
+    #[test]
+    fn references_to_options() {
+        let name = Some("hamcrest".to_string());
+        assert_that!(&name, some(equal_to(&"hamcrest".to_string())));
+        assert_that!(&name, not(some(equal_to(&"other".to_string()))));
+    }
+
+    #[test]
+    fn none_is_reported() {
+        let error = check_that(None::<i32>, some(equal_to(4))).unwrap_err();
+
+        assert_that!(error.expected(), equal_to("Some(4)"));
+        assert_that!(error.mismatch().to_string(), equal_to("was None".to_string()));
+    }
+
+    #[test]
+    fn inner_mismatch_is_reported() {
+        let error = check_that(Some(2), some(greater_than(3))).unwrap_err();
+
+        assert_that!(error.expected(), equal_to("Some(> 3)"));
+        assert_that!(error.mismatch().to_string(), equal_to("Some(...): was 2".to_string()));
+    }
+
+    #[test]
+    fn negation_describes_the_match() {
+        let error = check_that(Some(4), not(some(equal_to(4)))).unwrap_err();
+
+        assert_that!(
+            error.mismatch().to_string(),
+            equal_to("Some(...): was 4, which is 4".to_string())
+        );
+    }
+
+    #[test]
+    fn some_with_a_predicate() {
+        let even = |n: &i32| n % 2 == 0;
+        assert_that!(Some(4), some_with("an even number", even));
+        assert_that!(&Some(4), some_with("an even number", even));
+
+        let error = check_that(Some(3), some_with("an even number", even)).unwrap_err();
+        assert_that!(error.expected(), equal_to("Some(an even number)"));
+        assert_that!(error.mismatch().to_string(), equal_to("was Some(3)".to_string()));
+
+        let error = check_that(None, some_with("an even number", even)).unwrap_err();
+        assert_that!(error.mismatch().to_string(), equal_to("was None".to_string()));
+    }
+}
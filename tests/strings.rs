@@ -0,0 +1,220 @@
+// Copyright 2026 The hamcrest-rust Developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#[macro_use]
+extern crate hamcrest;
+
+mod strings {
+
+    use hamcrest::prelude::*;
+
+    fn mismatch<M: for<'a> HamcrestMatcher<&'a str>>(actual: &str, matcher: M) -> String {
+        matcher.matches(actual).unwrap_err().to_string()
+    }
+
+    #[test]
+    fn contains_string_matches_substrings() {
+        assert_that!("hello world", contains_string("o w"));
+        assert_that!(&"hello world".to_string(), contains_string("world"));
+        assert_that!("hello world", not(contains_string("word")));
+    }
+
+    #[test]
+    fn contains_string_shows_the_part_that_was_found() {
+        assert_that!(
+            mismatch("hello world", contains_string("worm")),
+            equal_to(
+                "was \"hello world\", which contains \"wor\" at index 6 but not \"worm\"".to_string()
+            )
+        );
+        assert_that!(
+            mismatch("hello", contains_string("xyz")),
+            equal_to("was \"hello\"".to_string())
+        );
+    }
+
+    #[test]
+    fn starts_with_and_ends_with() {
+        assert_that!("hello world", starts_with("hello"));
+        assert_that!(&"hello world".to_string(), ends_with("world"));
+        assert_that!("hello world", not(starts_with("world")));
+        assert_that!("hello world", not(ends_with("hello")));
+    }
+
+    #[test]
+    fn starts_with_shows_the_divergence() {
+        assert_that!(
+            mismatch("help me", starts_with("hello")),
+            equal_to("was \"help me\", which differs at index 3".to_string())
+        );
+        assert_that!(
+            mismatch("he", starts_with("hello")),
+            equal_to("was \"he\", which is missing \"llo\" at index 2".to_string())
+        );
+    }
+
+    #[test]
+    fn ends_with_shows_the_divergence() {
+        assert_that!(
+            mismatch("hello world", ends_with("word")),
+            equal_to("was \"hello world\", which differs at index 9".to_string())
+        );
+        assert_that!(
+            mismatch("world", ends_with("hello world")),
+            equal_to("was \"world\", which is missing \"hello \" at the start".to_string())
+        );
+    }
+
+    #[test]
+    fn equal_strings_of_any_lifetime() {
+        assert_that!("hello", equal_to_string("hello"));
+        assert_that!(&"hello".to_string(), equal_to_string("hello"));
+        assert_that!("hello", not(equal_to_string("help")));
+
+        assert_that!(
+            mismatch("help me", equal_to_string("hello")),
+            equal_to("was \"help me\", which differs at index 3".to_string())
+        );
+    }
+
+    #[test]
+    fn case_is_ignored() {
+        assert_that!("Hello World", equal_to_ignoring_case("hello world"));
+        assert_that!(&"ÄRGER".to_string(), equal_to_ignoring_case("ärger"));
+        assert_that!("hello", not(equal_to_ignoring_case("hello!")));
+
+        assert_that!(
+            mismatch("Hello Word", equal_to_ignoring_case("hello world")),
+            equal_to("was \"Hello Word\", which differs at index 9".to_string())
+        );
+        assert_that!(
+            mismatch("Hello World!", equal_to_ignoring_case("hello world")),
+            equal_to("was \"Hello World!\", which has \"!\" left over at index 11".to_string())
+        );
+    }
+
+    #[test]
+    fn whitespace_is_ignored() {
+        assert_that!("  hello \t\n world ", equal_to_ignoring_whitespace("hello world"));
+        assert_that!("helloworld", not(equal_to_ignoring_whitespace("hello world")));
+
+        assert_that!(
+            mismatch(" hello  word", equal_to_ignoring_whitespace("hello world")),
+            equal_to(
+                "was \" hello  word\", or \"hello word\" ignoring whitespace, which differs at index 9".to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn descriptions() {
+        assert_that!(
+            contains_string("a").to_string(),
+            equal_to("a string containing \"a\"".to_string())
+        );
+        assert_that!(
+            starts_with("a").to_string(),
+            equal_to("a string starting with \"a\"".to_string())
+        );
+        assert_that!(
+            ends_with("a").to_string(),
+            equal_to("a string ending with \"a\"".to_string())
+        );
+        assert_that!(
+            equal_to_ignoring_case("a").to_string(),
+            equal_to("\"a\" ignoring case".to_string())
+        );
+        assert_that!(
+            equal_to_ignoring_whitespace("a").to_string(),
+            equal_to("\"a\" ignoring whitespace".to_string())
+        );
+    }
+
+    #[test]
+    fn negation_describes_the_match() {
+        let error = check_that("hello", not(starts_with("he"))).unwrap_err();
+
+        assert_that!(
+            error.mismatch().to_string(),
+            equal_to("was \"hello\", which is a string starting with \"he\"".to_string())
+        );
+    }
+
+    #[test]
+    fn substrings_in_order() {
+        let log = "start\nload config\nlisten on 8080\nstop";
+
+        assert_that!(log, string_contains_in_order(vec!["start", "listen", "stop"]));
+        assert_that!(&log.to_string(), string_contains_in_order(["config", "8080"]));
+        assert_that!("abab", string_contains_in_order(["ab", "ab"]));
+        assert_that!("aba", not(string_contains_in_order(["ab", "ab"])));
+        assert_that!(log, not(string_contains_in_order(["listen", "config"])));
+    }
+
+    #[test]
+    fn substrings_in_order_reports_the_missing_one() {
+        assert_that!(
+            mismatch("start, listen, stop", string_contains_in_order(["listen", "start"])),
+            equal_to(
+                "was \"start, listen, stop\", which does not contain \"start\" after index 13".to_string()
+            )
+        );
+        assert_that!(
+            string_contains_in_order(["a", "b"]).to_string(),
+            equal_to("a string containing [\"a\", \"b\"] in order".to_string())
+        );
+    }
+
+    #[test]
+    fn empty_and_blank_strings() {
+        assert_that!("", empty_string());
+        assert_that!(&String::new(), empty_string());
+        assert_that!(" ", not(empty_string()));
+        assert_that!(" \t\n", blank_string());
+        assert_that!("", blank_string());
+
+        assert_that!(mismatch("a", empty_string()), equal_to("was \"a\"".to_string()));
+        assert_that!(
+            mismatch("  a ", blank_string()),
+            equal_to("was \"  a \", which has a non-whitespace character at index 2".to_string())
+        );
+    }
+
+    #[test]
+    fn empty_or_none_strings() {
+        assert_that!(None, empty_or_none_string());
+        assert_that!(Some(""), empty_or_none_string());
+        assert_that!(&Some(String::new()), empty_or_none_string());
+        assert_that!(&None::<String>, empty_or_none_string());
+
+        let error = check_that(Some("a"), empty_or_none_string()).unwrap_err();
+        assert_that!(error.expected(), equal_to("None or an empty string"));
+        assert_that!(error.mismatch().to_string(), equal_to("was Some(\"a\")".to_string()));
+    }
+
+    #[test]
+    fn trimmed_strings() {
+        assert_that!("  42\n", trimmed(equal_to("42")));
+        assert_that!(&" hello ".to_string(), trimmed(starts_with("hello")));
+        assert_that!(" \t", trimmed(empty_string()));
+
+        let error = check_that(" 41 ", trimmed(equal_to("42"))).unwrap_err();
+        assert_that!(error.expected(), equal_to("\"42\" when trimmed"));
+        assert_that!(error.mismatch().to_string(), equal_to("trimmed: was \"41\"".to_string()));
+    }
+
+    #[test]
+    fn empty_and_blank_descriptions() {
+        assert_that!(empty_string().to_string(), equal_to("an empty string".to_string()));
+        assert_that!(blank_string().to_string(), equal_to("a blank string".to_string()));
+        assert_that!(
+            trimmed(blank_string()).to_string(),
+            equal_to("a blank string when trimmed".to_string())
+        );
+    }
+}
@@ -11,6 +11,8 @@ extern crate hamcrest;
 
 mod vecs {
 
+    use std::collections::{BTreeSet, HashSet, LinkedList, VecDeque};
+
     use hamcrest::prelude::*;
 
     #[test]
@@ -47,10 +49,68 @@ mod vecs {
         assert_that!(&vec![1, 2, 3], not(contains(vec![2, 3]).in_order()));
     }
 
+    #[test]
+    fn is_match_agrees_with_matches() {
+        let actual = vec![1, 2, 3];
+        let matchers = vec![
+            contains(vec![3, 1]),
+            contains(vec![4]),
+            contains(vec![1, 2]).exactly(),
+            contains(vec![3, 2, 1]).exactly(),
+            contains(vec![1, 3]).in_order(),
+            contains(vec![3, 1]).in_order(),
+        ];
+        for matcher in matchers {
+            assert_that!(matcher.is_match(&actual), equal_to(matcher.matches(&actual).is_ok()));
+        }
+    }
+
     #[test]
     fn vec_of_len() {
         assert_that!(&vec![1, 2, 3], of_len(3));
         assert_that!(&vec![1, 2, 3], is(of_len(3)));
     }
 
+    #[test]
+    fn slices_and_arrays() {
+        let slice: &[i32] = &[1, 2, 3];
+
+        assert_that!(slice, contains(vec![3, 1]));
+        assert_that!(slice, contains(vec![2, 3]).in_order());
+        assert_that!(slice, of_len(3));
+        assert_that!(&[1, 2, 3], contains(vec![1, 2, 3]).exactly());
+        assert_that!(&[1, 2, 3], of_len(3));
+    }
+
+    #[test]
+    fn std_collections() {
+        let deque: VecDeque<i32> = vec![1, 2, 3].into_iter().collect();
+        let list: LinkedList<i32> = vec![1, 2, 3].into_iter().collect();
+        let hash_set: HashSet<i32> = vec![1, 2, 3].into_iter().collect();
+        let btree_set: BTreeSet<i32> = vec![3, 2, 1].into_iter().collect();
+
+        assert_that!(&deque, contains(vec![1, 2]).in_order());
+        assert_that!(&list, contains(vec![3, 2, 1]).exactly());
+        assert_that!(&hash_set, contains(vec![2, 3]));
+        assert_that!(&hash_set, not(contains(vec![4])));
+        assert_that!(&btree_set, contains(vec![1, 2, 3]).exactly().in_order());
+        assert_that!(&deque, of_len(3));
+        assert_that!(&list, of_len(3));
+        assert_that!(&hash_set, of_len(3));
+        assert_that!(&btree_set, not(of_len(2)));
+    }
+
+    #[test]
+    fn mismatches_show_the_collection() {
+        let btree_set: BTreeSet<i32> = vec![1, 2, 3].into_iter().collect();
+
+        let error = check_that(&btree_set, contains(vec![4])).unwrap_err();
+        assert_that!(error.mismatch().to_string(), equal_to("was {1, 2, 3}".to_string()));
+
+        let error = check_that(&btree_set, contains(vec![1]).exactly()).unwrap_err();
+        assert_that!(error.mismatch().to_string(), equal_to("also had [2, 3]".to_string()));
+
+        let error = check_that(&btree_set, of_len(2)).unwrap_err();
+        assert_that!(error.mismatch().to_string(), equal_to("was len 3".to_string()));
+    }
 }